@@ -0,0 +1,120 @@
+//! Compares the borrowed parse result with a fully owned copy of it. The
+//! `grammar` row runs only the pest `line` rule over every line, which is the
+//! floor for both.
+//!
+//! Run with `cargo bench`; prints heap allocations and time per parse of a
+//! log made from `lol_chat_example.txt` repeated many times.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use lol_chat_parser::{parse_log, LolChatParser, Rule};
+use pest::Parser;
+
+/// Counts every allocation made through the global allocator.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const EXAMPLE: &str = include_str!("../lol_chat_example.txt");
+const REPEAT: usize = 200;
+const ROUNDS: u32 = 20;
+
+struct Measurement {
+    allocations: usize,
+    bytes: usize,
+    time: Duration,
+}
+
+/// Average allocations, bytes and time of one call to `parse`.
+fn measure(parse: impl Fn()) -> Measurement {
+    parse();
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        parse();
+    }
+    let time = start.elapsed();
+
+    Measurement {
+        allocations: (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / ROUNDS as usize,
+        bytes: (ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes) / ROUNDS as usize,
+        time: time / ROUNDS,
+    }
+}
+
+fn report(name: &str, m: &Measurement) {
+    println!(
+        "{name:<10} {:>9} allocations {:>11} bytes {:>10.2?}",
+        m.allocations, m.bytes, m.time
+    );
+}
+
+fn main() {
+    let input = EXAMPLE.repeat(REPEAT);
+    println!(
+        "parsing {} lines ({} bytes), average of {ROUNDS} rounds",
+        input.lines().count(),
+        input.len()
+    );
+
+    let grammar = measure(|| {
+        for line in input.lines() {
+            let _ = black_box(LolChatParser::parse(Rule::line, black_box(line.trim())));
+        }
+    });
+    let borrowed = measure(|| {
+        black_box(parse_log(black_box(&input)));
+    });
+    let owned = measure(|| {
+        black_box(parse_log(black_box(&input)).into_owned());
+    });
+
+    report("grammar", &grammar);
+    report("borrowed", &borrowed);
+    report("owned", &owned);
+    println!(
+        "borrowed parse beyond the grammar: {} allocations ({:.1} per line)",
+        borrowed.allocations - grammar.allocations,
+        (borrowed.allocations - grammar.allocations) as f64 / input.lines().count() as f64
+    );
+    println!(
+        "into_owned adds {} allocations ({:.0}% of the borrowed parse) and {} bytes",
+        owned.allocations - borrowed.allocations,
+        percent(
+            owned.allocations - borrowed.allocations,
+            borrowed.allocations
+        ),
+        owned.bytes - borrowed.bytes
+    );
+}
+
+fn percent(part: usize, whole: usize) -> f64 {
+    100.0 * part as f64 / whole as f64
+}
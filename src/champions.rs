@@ -0,0 +1,212 @@
+/// Champion names as shown in the English client, sorted.
+pub const CHAMPIONS: &[&str] = &[
+    "Aatrox",
+    "Ahri",
+    "Akali",
+    "Akshan",
+    "Alistar",
+    "Ambessa",
+    "Amumu",
+    "Anivia",
+    "Annie",
+    "Aphelios",
+    "Ashe",
+    "Aurelion Sol",
+    "Aurora",
+    "Azir",
+    "Bard",
+    "Bel'Veth",
+    "Blitzcrank",
+    "Brand",
+    "Braum",
+    "Briar",
+    "Caitlyn",
+    "Camille",
+    "Cassiopeia",
+    "Cho'Gath",
+    "Corki",
+    "Darius",
+    "Diana",
+    "Dr. Mundo",
+    "Draven",
+    "Ekko",
+    "Elise",
+    "Evelynn",
+    "Ezreal",
+    "Fiddlesticks",
+    "Fiora",
+    "Fizz",
+    "Galio",
+    "Gangplank",
+    "Garen",
+    "Gnar",
+    "Gragas",
+    "Graves",
+    "Gwen",
+    "Hecarim",
+    "Heimerdinger",
+    "Hwei",
+    "Illaoi",
+    "Irelia",
+    "Ivern",
+    "Janna",
+    "Jarvan IV",
+    "Jax",
+    "Jayce",
+    "Jhin",
+    "Jinx",
+    "K'Sante",
+    "Kai'Sa",
+    "Kalista",
+    "Karma",
+    "Karthus",
+    "Kassadin",
+    "Katarina",
+    "Kayle",
+    "Kayn",
+    "Kennen",
+    "Kha'Zix",
+    "Kindred",
+    "Kled",
+    "Kog'Maw",
+    "LeBlanc",
+    "Lee Sin",
+    "Leona",
+    "Lillia",
+    "Lissandra",
+    "Lucian",
+    "Lulu",
+    "Lux",
+    "Malphite",
+    "Malzahar",
+    "Maokai",
+    "Master Yi",
+    "Mel",
+    "Milio",
+    "Miss Fortune",
+    "Mordekaiser",
+    "Morgana",
+    "Naafiri",
+    "Nami",
+    "Nasus",
+    "Nautilus",
+    "Neeko",
+    "Nidalee",
+    "Nilah",
+    "Nocturne",
+    "Nunu & Willump",
+    "Olaf",
+    "Orianna",
+    "Ornn",
+    "Pantheon",
+    "Poppy",
+    "Pyke",
+    "Qiyana",
+    "Quinn",
+    "Rakan",
+    "Rammus",
+    "Rek'Sai",
+    "Rell",
+    "Renata Glasc",
+    "Renekton",
+    "Rengar",
+    "Riven",
+    "Rumble",
+    "Ryze",
+    "Samira",
+    "Sejuani",
+    "Senna",
+    "Seraphine",
+    "Sett",
+    "Shaco",
+    "Shen",
+    "Shyvana",
+    "Singed",
+    "Sion",
+    "Sivir",
+    "Skarner",
+    "Smolder",
+    "Sona",
+    "Soraka",
+    "Swain",
+    "Sylas",
+    "Syndra",
+    "Tahm Kench",
+    "Taliyah",
+    "Talon",
+    "Taric",
+    "Teemo",
+    "Thresh",
+    "Tristana",
+    "Trundle",
+    "Tryndamere",
+    "Twisted Fate",
+    "Twitch",
+    "Udyr",
+    "Urgot",
+    "Varus",
+    "Vayne",
+    "Veigar",
+    "Vel'Koz",
+    "Vex",
+    "Vi",
+    "Viego",
+    "Viktor",
+    "Vladimir",
+    "Volibear",
+    "Warwick",
+    "Wukong",
+    "Xayah",
+    "Xerath",
+    "Xin Zhao",
+    "Yasuo",
+    "Yone",
+    "Yorick",
+    "Yunara",
+    "Yuumi",
+    "Zac",
+    "Zed",
+    "Zeri",
+    "Ziggs",
+    "Zilean",
+    "Zoe",
+    "Zyra",
+];
+
+/// Other spellings seen in logs and client data, mapped to [`CHAMPIONS`].
+const ALIASES: &[(&str, &str)] = &[
+    ("MonkeyKing", "Wukong"),
+    ("Nunu", "Nunu & Willump"),
+    ("Nunu and Willump", "Nunu & Willump"),
+    ("Renata", "Renata Glasc"),
+];
+
+/// Looks up the canonical spelling of a champion name.
+///
+/// Case, spaces and punctuation are ignored, so `"Kaisa"`, `"kai'sa"` and
+/// `"KAI'SA"` all map to `"Kai'Sa"`, and `"Dr Mundo"` to `"Dr. Mundo"`.
+pub fn canonical_champion(name: &str) -> Option<&'static str> {
+    // Most names in a log are already spelled canonically.
+    if let Ok(index) = CHAMPIONS.binary_search(&name) {
+        return Some(CHAMPIONS[index]);
+    }
+
+    CHAMPIONS
+        .iter()
+        .copied()
+        .find(|champion| lookup_key(champion).eq(lookup_key(name)))
+        .or_else(|| {
+            ALIASES
+                .iter()
+                .find(|(alias, _)| lookup_key(alias).eq(lookup_key(name)))
+                .map(|(_, champion)| *champion)
+        })
+}
+
+/// Characters compared by [`canonical_champion`]; yielded lazily so a
+/// lookup does not allocate.
+fn lookup_key(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+}
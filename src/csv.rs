@@ -0,0 +1,368 @@
+use std::fmt::Write;
+
+use crate::{
+    BountyStatus, ChatChannel, DragonElement, GameTime, KillerKind, LogEvent, Objective,
+    OutputOptions, ParsedLog, PingKind, SourceSpan, StreakLevel, Team, TimeFormat,
+};
+
+/// Which records of a [`ParsedLog`] [`to_csv_string`] writes, one row each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvTable {
+    Messages,
+    Kills,
+    /// Every non-kill event; columns that do not apply to an event's `type`
+    /// are left empty.
+    Events,
+    Players,
+}
+
+impl CsvTable {
+    pub const ALL: &'static [CsvTable] = &[
+        CsvTable::Messages,
+        CsvTable::Kills,
+        CsvTable::Events,
+        CsvTable::Players,
+    ];
+
+    /// Name used on the command line, e.g. `kills`.
+    pub fn name(self) -> &'static str {
+        match self {
+            CsvTable::Messages => "messages",
+            CsvTable::Kills => "kills",
+            CsvTable::Events => "events",
+            CsvTable::Players => "players",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<CsvTable> {
+        CsvTable::ALL
+            .iter()
+            .copied()
+            .find(|table| table.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Writes one table of `log` as CSV with a header row. Fields are quoted
+/// when they contain a comma, a quote or a line break, as in RFC 4180.
+///
+/// Times follow `options.time_format`; with `options.include_source` a last
+/// `line` column holds the input line number of each record.
+pub fn to_csv_string(log: &ParsedLog<'_>, table: CsvTable, options: OutputOptions) -> String {
+    let mut csv = Csv {
+        out: String::new(),
+        options,
+    };
+
+    match table {
+        CsvTable::Messages => {
+            csv.header(&["time", "channel", "player", "champion", "text"]);
+            for m in &log.messages {
+                csv.row(
+                    [
+                        csv.time(m.time),
+                        channel_name(m.channel).to_string(),
+                        m.player.to_string(),
+                        m.champion.to_string(),
+                        m.text.to_string(),
+                    ],
+                    m.source,
+                );
+            }
+        }
+        CsvTable::Kills => {
+            csv.header(&[
+                "time",
+                "killer",
+                "killer_champion",
+                "killer_kind",
+                "victim",
+                "victim_champion",
+                "bounty",
+                "bounty_text",
+                "bounty_status",
+                "is_shutdown",
+                "is_first_blood",
+                "is_execution",
+            ]);
+            for k in &log.kills {
+                csv.row(
+                    [
+                        csv.time(k.time),
+                        k.killer.as_deref().unwrap_or_default().to_string(),
+                        k.killer_champion.as_deref().unwrap_or_default().to_string(),
+                        killer_kind_name(k.killer_kind).to_string(),
+                        k.victim.as_deref().unwrap_or_default().to_string(),
+                        k.victim_champion.as_deref().unwrap_or_default().to_string(),
+                        k.bounty.map(|b| b.to_string()).unwrap_or_default(),
+                        k.bounty_text.as_deref().unwrap_or_default().to_string(),
+                        bounty_status_name(k.bounty_status).to_string(),
+                        k.is_shutdown.to_string(),
+                        k.is_first_blood.to_string(),
+                        k.is_execution.to_string(),
+                    ],
+                    k.source,
+                );
+            }
+        }
+        CsvTable::Events => {
+            csv.header(&[
+                "time",
+                "type",
+                "player",
+                "champion",
+                "target",
+                "target_champion",
+                "team",
+                "objective",
+                "element",
+                "item",
+                "ping",
+                "level",
+                "percent",
+                "feat",
+            ]);
+            for e in &log.events {
+                let event = EventColumns::new(&e.event);
+                let (player, champion) = e.event.actor().unwrap_or_default();
+                let (target, target_champion) = e.event.subject().unwrap_or_default();
+                csv.row(
+                    [
+                        csv.time(e.time),
+                        event.kind.to_string(),
+                        player.to_string(),
+                        champion.to_string(),
+                        target.to_string(),
+                        target_champion.to_string(),
+                        event.team.map_or("", team_name).to_string(),
+                        event.objective.to_string(),
+                        event.element.map_or("", element_name).to_string(),
+                        event.item.to_string(),
+                        event.ping.map_or("", ping_name).to_string(),
+                        event.level.map_or("", level_name).to_string(),
+                        event.percent.map(|p| p.to_string()).unwrap_or_default(),
+                        event.feat.to_string(),
+                    ],
+                    e.source,
+                );
+            }
+        }
+        CsvTable::Players => {
+            csv.options.include_source = false;
+            csv.header(&["name", "game_name", "tag_line", "champions"]);
+            for p in &log.players {
+                csv.row(
+                    [
+                        p.name.to_string(),
+                        p.riot_id.game_name.to_string(),
+                        p.riot_id
+                            .tag_line
+                            .as_deref()
+                            .unwrap_or_default()
+                            .to_string(),
+                        p.champions.join(", "),
+                    ],
+                    SourceSpan::default(),
+                );
+            }
+        }
+    }
+
+    csv.out
+}
+
+struct Csv {
+    out: String,
+    options: OutputOptions,
+}
+
+impl Csv {
+    fn header(&mut self, columns: &[&str]) {
+        let line = self.options.include_source.then_some("line");
+        self.write_fields(columns.iter().copied().chain(line));
+    }
+
+    fn row<const N: usize>(&mut self, fields: [String; N], source: SourceSpan) {
+        let line = self.options.include_source.then(|| source.line.to_string());
+        self.write_fields(fields.iter().map(String::as_str).chain(line.as_deref()));
+    }
+
+    fn time(&self, time: GameTime) -> String {
+        match self.options.time_format {
+            TimeFormat::Clock => time.to_string(),
+            TimeFormat::Seconds => time.as_seconds().to_string(),
+        }
+    }
+
+    fn write_fields<'f>(&mut self, fields: impl Iterator<Item = &'f str>) {
+        for (i, field) in fields.enumerate() {
+            if i > 0 {
+                self.out.push(',');
+            }
+            if field.contains([',', '"', '\n', '\r']) {
+                let _ = write!(self.out, "\"{}\"", field.replace('"', "\"\""));
+            } else {
+                self.out.push_str(field);
+            }
+        }
+        self.out.push('\n');
+    }
+}
+
+/// Event-specific columns of the `events` table, named as in the JSON output.
+#[derive(Default)]
+struct EventColumns<'e> {
+    kind: &'static str,
+    team: Option<Team>,
+    objective: &'e str,
+    element: Option<DragonElement>,
+    item: &'e str,
+    ping: Option<PingKind>,
+    level: Option<StreakLevel>,
+    percent: Option<u32>,
+    feat: &'e str,
+}
+
+impl<'e> EventColumns<'e> {
+    fn new(event: &'e LogEvent<'_>) -> Self {
+        match event {
+            LogEvent::Purchase { item, .. } => EventColumns {
+                kind: "purchase",
+                item,
+                ..EventColumns::default()
+            },
+            LogEvent::Ping { kind, .. } => EventColumns {
+                kind: "ping",
+                ping: Some(*kind),
+                ..EventColumns::default()
+            },
+            LogEvent::Streak { level, .. } => EventColumns {
+                kind: "streak",
+                level: Some(*level),
+                ..EventColumns::default()
+            },
+            LogEvent::StreakEnded { level, .. } => EventColumns {
+                kind: "streak_ended",
+                level: *level,
+                ..EventColumns::default()
+            },
+            LogEvent::TargetPlayer { .. } => EventColumns {
+                kind: "target_player",
+                ..EventColumns::default()
+            },
+            LogEvent::TargetObjective {
+                objective, percent, ..
+            } => EventColumns {
+                kind: "target_objective",
+                objective,
+                percent: Some(*percent),
+                ..EventColumns::default()
+            },
+            LogEvent::TeamFeat { team, feat } => EventColumns {
+                kind: "team_feat",
+                team: Some(*team),
+                feat,
+                ..EventColumns::default()
+            },
+            LogEvent::Objective {
+                team, objective, ..
+            } => EventColumns {
+                kind: "objective",
+                team: *team,
+                objective: objective_name(*objective),
+                element: match objective {
+                    Objective::Dragon { element } => *element,
+                    _ => None,
+                },
+                ..EventColumns::default()
+            },
+        }
+    }
+}
+
+fn channel_name(channel: ChatChannel) -> &'static str {
+    match channel {
+        ChatChannel::All => "all",
+        ChatChannel::Team => "team",
+        ChatChannel::Party => "party",
+        ChatChannel::Player => "player",
+    }
+}
+
+fn killer_kind_name(kind: KillerKind) -> &'static str {
+    match kind {
+        KillerKind::Champion => "champion",
+        KillerKind::Turret => "turret",
+        KillerKind::Minion => "minion",
+        KillerKind::Monster => "monster",
+        KillerKind::Unknown => "unknown",
+    }
+}
+
+fn bounty_status_name(status: BountyStatus) -> &'static str {
+    match status {
+        BountyStatus::Absent => "absent",
+        BountyStatus::Parsed => "parsed",
+        BountyStatus::Unparseable => "unparseable",
+    }
+}
+
+fn team_name(team: Team) -> &'static str {
+    match team {
+        Team::Enemy => "enemy",
+        Team::Ally => "ally",
+        Team::Blue => "blue",
+        Team::Red => "red",
+    }
+}
+
+fn objective_name(objective: Objective) -> &'static str {
+    match objective {
+        Objective::Dragon { .. } => "dragon",
+        Objective::ElderDragon => "elder_dragon",
+        Objective::BaronNashor => "baron_nashor",
+        Objective::RiftHerald => "rift_herald",
+        Objective::VoidGrubs => "void_grubs",
+        Objective::Atakhan => "atakhan",
+        Objective::TurretDestroyed => "turret_destroyed",
+        Objective::InhibitorDestroyed => "inhibitor_destroyed",
+        Objective::NexusTurretDestroyed => "nexus_turret_destroyed",
+        Objective::InhibitorRespawned => "inhibitor_respawned",
+        Objective::Ace => "ace",
+    }
+}
+
+fn element_name(element: DragonElement) -> &'static str {
+    match element {
+        DragonElement::Infernal => "infernal",
+        DragonElement::Mountain => "mountain",
+        DragonElement::Ocean => "ocean",
+        DragonElement::Cloud => "cloud",
+        DragonElement::Hextech => "hextech",
+        DragonElement::Chemtech => "chemtech",
+    }
+}
+
+fn ping_name(kind: PingKind) -> &'static str {
+    match kind {
+        PingKind::OnTheWay => "on_the_way",
+        PingKind::Missing => "missing",
+        PingKind::Retreating => "retreating",
+        PingKind::InDanger => "in_danger",
+        PingKind::NeedsVision => "needs_vision",
+    }
+}
+
+fn level_name(level: StreakLevel) -> &'static str {
+    match level {
+        StreakLevel::DoubleKill => "double_kill",
+        StreakLevel::TripleKill => "triple_kill",
+        StreakLevel::QuadraKill => "quadra_kill",
+        StreakLevel::PentaKill => "penta_kill",
+        StreakLevel::KillingSpree => "killing_spree",
+        StreakLevel::Rampage => "rampage",
+        StreakLevel::Unstoppable => "unstoppable",
+        StreakLevel::Dominating => "dominating",
+        StreakLevel::Godlike => "godlike",
+        StreakLevel::Legendary => "legendary",
+    }
+}
@@ -0,0 +1,109 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{owned, Rule};
+
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Expected noise, e.g. the untimed "Type /help" banner, or a value kept
+    /// as written, such as a champion missing from the built-in table.
+    Info,
+    /// Only part of the line was understood, typically a new message format.
+    Warning,
+    /// The line looked like a log entry but could not be parsed.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// A note about one input line that was not fully recognized.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct Diagnostic<'a> {
+    pub severity: Severity,
+    /// 1-based input line number.
+    pub line: usize,
+    /// Grammar rule that was attempted or that matched as a fallback.
+    #[serde(
+        serialize_with = "serialize_rule",
+        deserialize_with = "deserialize_rule"
+    )]
+    #[schemars(with = "String")]
+    pub rule: Rule,
+    /// Pest error message, or a description of what was left unrecognized.
+    pub message: Cow<'static, str>,
+    /// Original trimmed line text.
+    pub text: Cow<'a, str>,
+}
+
+impl Diagnostic<'_> {
+    pub fn into_owned(self) -> Diagnostic<'static> {
+        Diagnostic {
+            severity: self.severity,
+            line: self.line,
+            rule: self.rule,
+            message: self.message,
+            text: owned(self.text),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {} in `{:?}`: {} (`{}`)",
+            self.line, self.severity, self.rule, self.message, self.text
+        )
+    }
+}
+
+fn serialize_rule<S: Serializer>(rule: &Rule, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{rule:?}"))
+}
+
+fn deserialize_rule<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rule, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    Rule::all_rules()
+        .iter()
+        .find(|rule| format!("{rule:?}") == name)
+        .copied()
+        .ok_or_else(|| de::Error::custom(format_args!("unknown grammar rule `{name}`")))
+}
+
+/// Returned by [`parse_log_strict`](crate::parse_log_strict) when any line
+/// produced a warning or an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseErrors {
+    pub diagnostics: Vec<Diagnostic<'static>>,
+}
+
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} line(s) were not fully parsed",
+            self.diagnostics.len()
+        )?;
+        for diagnostic in &self.diagnostics {
+            write!(f, "\n  {diagnostic}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseErrors {}
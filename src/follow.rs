@@ -0,0 +1,105 @@
+use std::fs::{self, File, Metadata};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use crate::{LogParser, ParsedLine};
+
+/// Follows a log file that is still being written, like `tail -F`.
+///
+/// Each [`poll`](LogFollower::poll) returns the complete lines appended
+/// since the previous call; a line without its terminator waits for the
+/// next poll. When the file shrinks or the path points to a new file, it is
+/// reopened from the start with a fresh parser.
+#[derive(Debug)]
+pub struct LogFollower {
+    path: PathBuf,
+    reader: BufReader<File>,
+    /// Bytes consumed from the current file, including `pending`.
+    position: u64,
+    file_id: Option<u64>,
+    pending: String,
+    initial: LogParser<'static>,
+    parser: LogParser<'static>,
+}
+
+impl LogFollower {
+    /// Opens `path` and starts reading at its beginning; `parser` is cloned
+    /// again whenever the file is truncated or replaced.
+    pub fn open(path: impl AsRef<Path>, parser: LogParser<'static>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = File::open(&path)?;
+        let file_id = file_id(&file.metadata()?);
+
+        Ok(LogFollower {
+            path,
+            reader: BufReader::new(file),
+            position: 0,
+            file_id,
+            pending: String::new(),
+            parser: parser.clone(),
+            initial: parser,
+        })
+    }
+
+    /// The parser for the current file, e.g. to read its player registry.
+    pub fn parser(&self) -> &LogParser<'static> {
+        &self.parser
+    }
+
+    /// Parses every complete line written since the last poll.
+    pub fn poll(&mut self) -> io::Result<Vec<ParsedLine<'static>>> {
+        if self.was_replaced() {
+            self.reopen()?;
+        }
+
+        let mut parsed = Vec::new();
+        loop {
+            let read = self.reader.read_line(&mut self.pending)?;
+            if read == 0 {
+                break;
+            }
+            self.position += read as u64;
+            if !self.pending.ends_with('\n') {
+                break;
+            }
+
+            parsed.extend(self.parser.feed_line_owned(&self.pending));
+            self.pending.clear();
+        }
+
+        Ok(parsed)
+    }
+
+    /// Whether the path now holds a shorter or a different file. A missing
+    /// file (mid-rotation) is not a replacement yet.
+    fn was_replaced(&self) -> bool {
+        let Ok(metadata) = fs::metadata(&self.path) else {
+            return false;
+        };
+
+        metadata.len() < self.position || file_id(&metadata) != self.file_id
+    }
+
+    fn reopen(&mut self) -> io::Result<()> {
+        let file = File::open(&self.path)?;
+        self.file_id = file_id(&file.metadata()?);
+
+        self.reader = BufReader::new(file);
+        self.position = 0;
+        self.pending.clear();
+        self.parser = self.initial.clone();
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn file_id(metadata: &Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    Some(metadata.ino())
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &Metadata) -> Option<u64> {
+    None
+}
@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub};
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, Error};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::output::GAME_TIME;
+
+/// How [`GameTime`] values are written by `serde`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// `"MM:SS"` string, the same form as in the log.
+    #[default]
+    Clock,
+    /// Whole seconds since game start.
+    Seconds,
+}
+
+/// In-game clock: whole seconds since game start.
+///
+/// Displays (and serializes by default) back to the `MM:SS` form used in
+/// the log; games past 99 minutes keep counting minutes (`100:12`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GameTime(u32);
+
+impl GameTime {
+    pub const ZERO: GameTime = GameTime(0);
+
+    pub const fn from_seconds(seconds: u32) -> Self {
+        GameTime(seconds)
+    }
+
+    pub const fn from_minutes_seconds(minutes: u32, seconds: u32) -> Self {
+        GameTime(minutes * 60 + seconds)
+    }
+
+    pub const fn as_seconds(self) -> u32 {
+        self.0
+    }
+
+    /// Whole minutes elapsed, e.g. `13` for `13:05`.
+    pub const fn minutes(self) -> u32 {
+        self.0 / 60
+    }
+
+    /// Seconds past the last whole minute, e.g. `5` for `13:05`.
+    pub const fn seconds(self) -> u32 {
+        self.0 % 60
+    }
+
+    /// Rounds down to the start of the bucket of the given width, e.g. a
+    /// width of five minutes maps `13:05` to `10:00`.
+    pub fn bucket(self, width: GameTime) -> GameTime {
+        if width.0 == 0 {
+            return self;
+        }
+        GameTime(self.0 - self.0 % width.0)
+    }
+
+    pub fn checked_sub(self, rhs: GameTime) -> Option<GameTime> {
+        self.0.checked_sub(rhs.0).map(GameTime)
+    }
+
+    /// Interval between two times regardless of their order.
+    pub fn abs_diff(self, other: GameTime) -> GameTime {
+        GameTime(self.0.abs_diff(other.0))
+    }
+}
+
+impl fmt::Display for GameTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.minutes(), self.seconds())
+    }
+}
+
+/// Accepts `MM:SS` with any number of minute digits and `H:MM:SS`; seconds
+/// (and minutes, when hours are present) must be below 60.
+impl FromStr for GameTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        let (hours, minutes, seconds) = match parts.as_slice() {
+            [m, sec] => ("0", *m, *sec),
+            [h, m, sec] => (*h, *m, *sec),
+            _ => {
+                return Err(anyhow!(
+                    "Invalid game time `{s}`: expected MM:SS or H:MM:SS"
+                ))
+            }
+        };
+
+        let hours = parse_component(s, "hours", hours)?;
+        let minutes = parse_component(s, "minutes", minutes)?;
+        let seconds = parse_component(s, "seconds", seconds)?;
+
+        if parts[parts.len() - 1].len() != 2 || seconds >= 60 {
+            return Err(anyhow!(
+                "Invalid seconds in game time `{s}`: expected two digits below 60"
+            ));
+        }
+        if parts.len() == 3 && (parts[1].len() != 2 || minutes >= 60) {
+            return Err(anyhow!(
+                "Invalid minutes in game time `{s}`: expected two digits below 60"
+            ));
+        }
+
+        hours
+            .checked_mul(60)
+            .and_then(|m| m.checked_add(minutes))
+            .and_then(|m| m.checked_mul(60))
+            .and_then(|sec| sec.checked_add(seconds))
+            .map(GameTime)
+            .ok_or_else(|| anyhow!("Game time `{s}` is out of range"))
+    }
+}
+
+fn parse_component(time: &str, name: &str, value: &str) -> Result<u32, Error> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(anyhow!("Invalid {name} in game time `{time}`"));
+    }
+    value
+        .parse()
+        .map_err(|e| anyhow!("Invalid {name} in game time `{time}`: {e}"))
+}
+
+impl Add for GameTime {
+    type Output = GameTime;
+
+    fn add(self, rhs: GameTime) -> GameTime {
+        GameTime(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for GameTime {
+    fn add_assign(&mut self, rhs: GameTime) {
+        self.0 += rhs.0;
+    }
+}
+
+/// Saturates at [`GameTime::ZERO`]; use [`GameTime::checked_sub`] to detect
+/// a negative interval.
+impl Sub for GameTime {
+    type Output = GameTime;
+
+    fn sub(self, rhs: GameTime) -> GameTime {
+        GameTime(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl From<GameTime> for Duration {
+    fn from(time: GameTime) -> Duration {
+        Duration::from_secs(u64::from(time.0))
+    }
+}
+
+/// Writes the clock text; [`OutputOptions`](crate::OutputOptions) can
+/// switch to seconds.
+impl Serialize for GameTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(GAME_TIME, &Clock(*self))
+    }
+}
+
+struct Clock(GameTime);
+
+impl Serialize for Clock {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+/// Accepts either [`TimeFormat`].
+impl<'de> Deserialize<'de> for GameTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = GameTime;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an `MM:SS` string or whole seconds")
+            }
+
+            fn visit_u64<E: de::Error>(self, seconds: u64) -> Result<GameTime, E> {
+                u32::try_from(seconds)
+                    .map(GameTime)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(seconds), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, clock: &str) -> Result<GameTime, E> {
+                clock.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+impl JsonSchema for GameTime {
+    fn schema_name() -> Cow<'static, str> {
+        "GameTime".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Game clock as `MM:SS`, or whole seconds since game start.",
+            "oneOf": [
+                { "type": "string", "pattern": "^[0-9]+:[0-5][0-9]$" },
+                { "type": "integer", "minimum": 0, "maximum": u32::MAX }
+            ]
+        })
+    }
+}
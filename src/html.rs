@@ -0,0 +1,82 @@
+use crate::{to_json_string, OutputOptions, ParsedLog};
+
+/// Page with `{{title}}`, `{{log}}` and `{{lines}}` placeholders.
+const VIEWER: &str = include_str!("viewer.html");
+
+/// Builds a single static HTML page with an interactive timeline of `log`:
+/// records can be filtered by category, player and chat channel, and a time
+/// axis jumps to any moment.
+///
+/// The page has no external assets. It embeds `log` as the same JSON that
+/// `parse` prints, in a `<script id="parsed-log">` element, together with the
+/// canonical line of every timeline entry.
+pub fn render_html(log: &ParsedLog<'_>, title: &str) -> serde_json::Result<String> {
+    let json = to_json_string(log, OutputOptions::default())?;
+    let lines: Vec<String> = log
+        .timeline
+        .iter()
+        .map(|r| {
+            log.record(r)
+                .map(|record| record.to_string())
+                .unwrap_or_default()
+        })
+        .collect();
+    let lines = serde_json::to_string(&lines)?;
+
+    Ok(fill(
+        VIEWER,
+        &[
+            ("title", &escape_html(title)),
+            ("log", &script_data(&json)),
+            ("lines", &script_data(&lines)),
+        ],
+    ))
+}
+
+/// Replaces every `{{name}}` in one pass, so values are never scanned for
+/// placeholders themselves.
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let value = after.find("}}").and_then(|end| {
+            let (_, value) = values.iter().find(|(name, _)| *name == &after[..end])?;
+            Some((value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                out.push_str(value);
+                rest = &after[end + 2..];
+            }
+            None => {
+                out.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// JSON that cannot end its `<script>` element early: `<` only appears inside
+/// strings, where the `\u003c` escape reads back as the same character.
+fn script_data(json: &str) -> String {
+    json.replace('<', "\\u003c")
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
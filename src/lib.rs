@@ -1,15 +1,49 @@
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 use anyhow::{anyhow, Result};
+use pest::iterators::Pair;
 use pest::Parser;
 use pest_derive::Parser;
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+
+mod champions;
+mod csv;
+mod diagnostics;
+mod follow;
+mod game_time;
+mod html;
+mod locale;
+mod output;
+mod render;
+mod report;
+mod schema;
+mod stats;
+mod streaming;
+
+pub use champions::{canonical_champion, CHAMPIONS};
+pub use csv::{to_csv_string, CsvTable};
+pub use diagnostics::{Diagnostic, ParseErrors, Severity};
+pub use follow::LogFollower;
+pub use game_time::{GameTime, TimeFormat};
+pub use html::render_html;
+pub use locale::Locale;
+pub use output::{
+    to_json_string, to_json_string_pretty, to_ndjson_string, JsonLine, OutputOptions, WithOptions,
+};
+pub use render::render_log;
+pub use report::{render_report, ReportFormat};
+pub use schema::{json_schema, SCHEMA_VERSION};
+pub use stats::{player_stats, MessageCounts, PingCounts, PlayerStats};
+pub use streaming::{LogParser, LogRecord, ParsedLine, Records};
 
 #[derive(Parser)]
 #[grammar = "grammar.pest"]
-
 pub struct LolChatParser;
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ChatChannel {
     All,
@@ -18,342 +52,1430 @@ pub enum ChatChannel {
     Player,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
-pub struct ChatMessage {
-    pub time: String,
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ChatMessage<'a> {
+    pub time: GameTime,
     pub channel: ChatChannel,
-    pub player: String,
-    pub champion: String,
-    pub text: String,
-}
-
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
-pub struct KillEvent {
-    pub time: String,
-    pub killer: String,
-    pub killer_champion: String,
-    pub victim: Option<String>,
-    pub victim_champion: Option<String>,
+    pub player: Cow<'a, str>,
+    pub champion: Cow<'a, str>,
+    pub text: Cow<'a, str>,
+    #[serde(default)]
+    pub source: SourceSpan,
+}
+
+impl ChatMessage<'_> {
+    /// Copies every borrowed field, detaching the message from the input.
+    pub fn into_owned(self) -> ChatMessage<'static> {
+        ChatMessage {
+            time: self.time,
+            channel: self.channel,
+            player: owned(self.player),
+            champion: owned(self.champion),
+            text: owned(self.text),
+            source: self.source,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum KillerKind {
+    Champion,
+    Turret,
+    Minion,
+    Monster,
+    Unknown,
+}
+
+/// Whether a kill line carried a bounty section and if its amount was read.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BountyStatus {
+    /// No bounty section in the line.
+    #[default]
+    Absent,
+    Parsed,
+    /// A bounty section was present but no gold amount could be read.
+    Unparseable,
+}
+
+/// A kill, first blood, shutdown or execution.
+///
+/// `killer` is the player name for champion kills and the phrase from the
+/// log (e.g. "An enemy turret") otherwise; `killer_champion` is only set for
+/// champion kills.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct KillEvent<'a> {
+    pub time: GameTime,
+    pub killer: Option<Cow<'a, str>>,
+    pub killer_champion: Option<Cow<'a, str>>,
+    pub killer_kind: KillerKind,
+    pub victim: Option<Cow<'a, str>>,
+    pub victim_champion: Option<Cow<'a, str>>,
     pub bounty: Option<u32>,
+    /// Raw text inside the bounty parentheses, e.g. "Bonus Bounty: 1,050G".
+    pub bounty_text: Option<Cow<'a, str>>,
+    pub bounty_status: BountyStatus,
     pub is_shutdown: bool,
     pub is_first_blood: bool,
+    pub is_execution: bool,
+    #[serde(default)]
+    pub source: SourceSpan,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
-pub struct ObjectiveEvent {
-    pub time: String,
-    pub team: Option<String>,
-    pub description: String,
+impl KillEvent<'_> {
+    pub fn into_owned(self) -> KillEvent<'static> {
+        KillEvent {
+            time: self.time,
+            killer: self.killer.map(owned),
+            killer_champion: self.killer_champion.map(owned),
+            killer_kind: self.killer_kind,
+            victim: self.victim.map(owned),
+            victim_champion: self.victim_champion.map(owned),
+            bounty: self.bounty,
+            bounty_text: self.bounty_text.map(owned),
+            bounty_status: self.bounty_status,
+            is_shutdown: self.is_shutdown,
+            is_first_blood: self.is_first_blood,
+            is_execution: self.is_execution,
+            source: self.source,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
-pub struct PlayerSummary {
-    pub name: String,
-    pub champions: Vec<String>,
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Team {
+    Enemy,
+    Ally,
+    Blue,
+    Red,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
-pub struct SystemLine {
-    pub text: String,
+impl Team {
+    /// The other side: ally and enemy, blue and red.
+    pub fn opponent(self) -> Team {
+        match self {
+            Team::Enemy => Team::Ally,
+            Team::Ally => Team::Enemy,
+            Team::Blue => Team::Red,
+            Team::Red => Team::Blue,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
-pub struct ParsedLog {
-    pub players: Vec<PlayerSummary>,
-    pub kills: Vec<KillEvent>,
-    pub events: Vec<ObjectiveEvent>,
-    pub messages: Vec<ChatMessage>,
-    pub system: Vec<SystemLine>,
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DragonElement {
+    Infernal,
+    Mountain,
+    Ocean,
+    Cloud,
+    Hextech,
+    Chemtech,
 }
 
-pub fn parse_timestamp(line: &str) -> Result<String> {
-    let (time, _rest) = parse_time_and_rest(line)?;
-    Ok(time)
+/// Epic monster, structure or ace announcement, tagged by `kind` in JSON.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Objective {
+    Dragon { element: Option<DragonElement> },
+    ElderDragon,
+    BaronNashor,
+    RiftHerald,
+    VoidGrubs,
+    Atakhan,
+    TurretDestroyed,
+    InhibitorDestroyed,
+    NexusTurretDestroyed,
+    InhibitorRespawned,
+    Ace,
 }
 
-fn parse_time_and_rest(line: &str) -> Result<(String, String)> {
-    let mut pairs = LolChatParser::parse(Rule::line, line)
-        .map_err(|e| anyhow!("Parse error for `line`: {e}"))?;
-    let line_pair = pairs.next().ok_or_else(|| anyhow!("No match for `line`"))?;
-    let mut inner = line_pair.into_inner();
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PingKind {
+    OnTheWay,
+    Missing,
+    Retreating,
+    InDanger,
+    NeedsVision,
+}
 
-    let time_pair = inner
-        .next()
-        .ok_or_else(|| anyhow!("No `time` pair inside `line`"))?;
-    debug_assert_eq!(time_pair.as_rule(), Rule::time);
+/// Multikill or kill-streak announcement level.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StreakLevel {
+    DoubleKill,
+    TripleKill,
+    QuadraKill,
+    PentaKill,
+    KillingSpree,
+    Rampage,
+    Unstoppable,
+    Dominating,
+    Godlike,
+    Legendary,
+}
 
-    let time = time_pair.as_str().to_string();
+impl StreakLevel {
+    /// `true` for double through penta kills, `false` for kill streaks.
+    pub fn is_multikill(self) -> bool {
+        matches!(
+            self,
+            StreakLevel::DoubleKill
+                | StreakLevel::TripleKill
+                | StreakLevel::QuadraKill
+                | StreakLevel::PentaKill
+        )
+    }
+}
 
-    let rest = if let Some(idx) = line.find(' ') {
-        if idx + 1 < line.len() {
-            line[idx + 1..].to_string()
-        } else {
-            String::new()
+/// Typed payload of a non-kill event line, tagged by `type` in JSON.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LogEvent<'a> {
+    Purchase {
+        player: Cow<'a, str>,
+        champion: Cow<'a, str>,
+        item: Cow<'a, str>,
+    },
+    Ping {
+        player: Cow<'a, str>,
+        champion: Cow<'a, str>,
+        kind: PingKind,
+    },
+    Streak {
+        player: Cow<'a, str>,
+        champion: Cow<'a, str>,
+        level: StreakLevel,
+    },
+    /// `player` ended `victim`'s streak; `level` is unset for a plain
+    /// "streak" announcement.
+    StreakEnded {
+        player: Cow<'a, str>,
+        champion: Cow<'a, str>,
+        victim: Cow<'a, str>,
+        victim_champion: Cow<'a, str>,
+        level: Option<StreakLevel>,
+    },
+    TargetPlayer {
+        player: Cow<'a, str>,
+        champion: Cow<'a, str>,
+        target: Cow<'a, str>,
+        target_champion: Cow<'a, str>,
+    },
+    TargetObjective {
+        player: Cow<'a, str>,
+        champion: Cow<'a, str>,
+        objective: Cow<'a, str>,
+        percent: u32,
+    },
+    TeamFeat {
+        team: Team,
+        feat: Cow<'a, str>,
+    },
+    /// `team` is the side that secured the objective (or owns the
+    /// respawning inhibitor), when the line says so.
+    Objective {
+        team: Option<Team>,
+        player: Option<Cow<'a, str>>,
+        champion: Option<Cow<'a, str>>,
+        objective: Objective,
+    },
+}
+
+impl<'a> LogEvent<'a> {
+    /// Player and champion performing the event, if the event has one.
+    pub fn actor(&self) -> Option<(&str, &str)> {
+        self.actor_names().map(|(p, c)| (p.as_ref(), c.as_ref()))
+    }
+
+    /// Other player the event is about: the targeted player or the player
+    /// whose streak was ended.
+    pub fn subject(&self) -> Option<(&str, &str)> {
+        self.subject_names().map(|(p, c)| (p.as_ref(), c.as_ref()))
+    }
+
+    pub fn into_owned(self) -> LogEvent<'static> {
+        match self {
+            LogEvent::Purchase {
+                player,
+                champion,
+                item,
+            } => LogEvent::Purchase {
+                player: owned(player),
+                champion: owned(champion),
+                item: owned(item),
+            },
+            LogEvent::Ping {
+                player,
+                champion,
+                kind,
+            } => LogEvent::Ping {
+                player: owned(player),
+                champion: owned(champion),
+                kind,
+            },
+            LogEvent::Streak {
+                player,
+                champion,
+                level,
+            } => LogEvent::Streak {
+                player: owned(player),
+                champion: owned(champion),
+                level,
+            },
+            LogEvent::StreakEnded {
+                player,
+                champion,
+                victim,
+                victim_champion,
+                level,
+            } => LogEvent::StreakEnded {
+                player: owned(player),
+                champion: owned(champion),
+                victim: owned(victim),
+                victim_champion: owned(victim_champion),
+                level,
+            },
+            LogEvent::TargetPlayer {
+                player,
+                champion,
+                target,
+                target_champion,
+            } => LogEvent::TargetPlayer {
+                player: owned(player),
+                champion: owned(champion),
+                target: owned(target),
+                target_champion: owned(target_champion),
+            },
+            LogEvent::TargetObjective {
+                player,
+                champion,
+                objective,
+                percent,
+            } => LogEvent::TargetObjective {
+                player: owned(player),
+                champion: owned(champion),
+                objective: owned(objective),
+                percent,
+            },
+            LogEvent::TeamFeat { team, feat } => LogEvent::TeamFeat {
+                team,
+                feat: owned(feat),
+            },
+            LogEvent::Objective {
+                team,
+                player,
+                champion,
+                objective,
+            } => LogEvent::Objective {
+                team,
+                player: player.map(owned),
+                champion: champion.map(owned),
+                objective,
+            },
         }
-    } else {
-        String::new()
-    };
+    }
 
-    Ok((time, rest))
+    fn actor_names(&self) -> Option<(&Cow<'a, str>, &Cow<'a, str>)> {
+        match self {
+            LogEvent::Purchase {
+                player, champion, ..
+            }
+            | LogEvent::Ping {
+                player, champion, ..
+            }
+            | LogEvent::Streak {
+                player, champion, ..
+            }
+            | LogEvent::StreakEnded {
+                player, champion, ..
+            }
+            | LogEvent::TargetPlayer {
+                player, champion, ..
+            }
+            | LogEvent::TargetObjective {
+                player, champion, ..
+            } => Some((player, champion)),
+            LogEvent::Objective {
+                player: Some(player),
+                champion: Some(champion),
+                ..
+            } => Some((player, champion)),
+            LogEvent::TeamFeat { .. } | LogEvent::Objective { .. } => None,
+        }
+    }
+
+    fn subject_names(&self) -> Option<(&Cow<'a, str>, &Cow<'a, str>)> {
+        match self {
+            LogEvent::TargetPlayer {
+                target,
+                target_champion,
+                ..
+            } => Some((target, target_champion)),
+            LogEvent::StreakEnded {
+                victim,
+                victim_champion,
+                ..
+            } => Some((victim, victim_champion)),
+            _ => None,
+        }
+    }
 }
 
-pub fn parse_log(input: &str) -> ParsedLog {
-    let mut players: HashMap<String, HashSet<String>> = HashMap::new();
-    let mut kills = Vec::new();
-    let mut events = Vec::new();
-    let mut messages = Vec::new();
-    let mut system = Vec::new();
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ObjectiveEvent<'a> {
+    pub time: GameTime,
+    #[serde(flatten)]
+    pub event: LogEvent<'a>,
+    #[serde(default)]
+    pub source: SourceSpan,
+}
 
-    for line in input.lines() {
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
+impl ObjectiveEvent<'_> {
+    pub fn into_owned(self) -> ObjectiveEvent<'static> {
+        ObjectiveEvent {
+            time: self.time,
+            event: self.event.into_owned(),
+            source: self.source,
         }
+    }
+}
 
-        let (time, rest) = match parse_time_and_rest(trimmed) {
-            Ok(tr) => tr,
-            Err(_) => {
-                system.push(SystemLine {
-                    text: trimmed.to_string(),
-                });
-                continue;
+/// Riot ID split into its game name and optional `#TAG` line, e.g.
+/// `Some Name#EUW1`.
+#[derive(
+    Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct RiotId<'a> {
+    pub game_name: Cow<'a, str>,
+    pub tag_line: Option<Cow<'a, str>>,
+}
+
+impl<'a> RiotId<'a> {
+    /// Splits a `player_name` at its `#`; names without a tag keep the
+    /// whole text as the game name.
+    pub fn parse(name: &'a str) -> RiotId<'a> {
+        match name.rsplit_once('#') {
+            Some((game_name, tag_line)) if !game_name.is_empty() && !tag_line.is_empty() => {
+                RiotId {
+                    game_name: Cow::Borrowed(game_name),
+                    tag_line: Some(Cow::Borrowed(tag_line)),
+                }
             }
-        };
+            _ => RiotId {
+                game_name: Cow::Borrowed(name),
+                tag_line: None,
+            },
+        }
+    }
 
-        if let Some(chat) = parse_chat_message(&time, &rest) {
-            add_player(&mut players, &chat.player, &chat.champion);
-            messages.push(chat);
-            continue;
+    pub fn into_owned(self) -> RiotId<'static> {
+        RiotId {
+            game_name: owned(self.game_name),
+            tag_line: self.tag_line.map(owned),
         }
+    }
 
-        if let Some(kill) = parse_kill_event(&time, &rest) {
-            add_player(&mut players, &kill.killer, &kill.killer_champion);
-            if let (Some(victim), Some(vchamp)) = (&kill.victim, &kill.victim_champion) {
-                add_player(&mut players, victim, vchamp);
-            }
-            kills.push(kill);
-            continue;
+    /// Like [`RiotId::parse`], borrowing only when `name` does.
+    fn from_name(name: &Cow<'a, str>) -> RiotId<'a> {
+        match name {
+            Cow::Borrowed(name) => RiotId::parse(name),
+            Cow::Owned(name) => RiotId::parse(name).into_owned(),
         }
+    }
+}
 
-        if let Some(obj) = parse_objective_event(&time, &rest) {
-            if let Some((player, champ, _)) = parse_player_with_champion_prefix(&rest) {
-                add_player(&mut players, &player, &champ);
-            }
+impl std::fmt::Display for RiotId<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.tag_line {
+            Some(tag) => write!(f, "{}#{}", self.game_name, tag),
+            None => f.write_str(&self.game_name),
+        }
+    }
+}
 
-            if let Some((target_player, target_champion)) = parse_target_player(&rest) {
-                add_player(&mut players, &target_player, &target_champion);
-            }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PlayerSummary<'a> {
+    /// Full name as it appears in the log, including any `#TAG`.
+    pub name: Cow<'a, str>,
+    #[serde(flatten)]
+    pub riot_id: RiotId<'a>,
+    pub champions: Vec<Cow<'a, str>>,
+}
 
-            events.push(obj);
-            continue;
+impl PlayerSummary<'_> {
+    pub fn into_owned(self) -> PlayerSummary<'static> {
+        PlayerSummary {
+            name: owned(self.name),
+            riot_id: self.riot_id.into_owned(),
+            champions: self.champions.into_iter().map(owned).collect(),
         }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SystemLine<'a> {
+    pub text: Cow<'a, str>,
+    #[serde(default)]
+    pub source: SourceSpan,
+}
 
-        if let Some((player, champ, _)) = parse_player_with_champion_prefix(&rest) {
-            add_player(&mut players, &player, &champ);
+impl SystemLine<'_> {
+    pub fn into_owned(self) -> SystemLine<'static> {
+        SystemLine {
+            text: owned(self.text),
+            source: self.source,
         }
     }
+}
 
-    let mut players_vec: Vec<PlayerSummary> = players
-        .into_iter()
-        .map(|(name, champs)| {
-            let mut list: Vec<String> = champs.into_iter().collect();
-            list.sort();
-            PlayerSummary {
-                name,
-                champions: list,
+/// Timestamped line that matched only a fallback rule.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct UnclassifiedLine<'a> {
+    pub time: GameTime,
+    pub player: Option<Cow<'a, str>>,
+    pub champion: Option<Cow<'a, str>>,
+    /// Text after the timestamp, or after `player (champion)` when present.
+    pub text: Cow<'a, str>,
+    #[serde(default)]
+    pub source: SourceSpan,
+}
+
+impl UnclassifiedLine<'_> {
+    pub fn into_owned(self) -> UnclassifiedLine<'static> {
+        UnclassifiedLine {
+            time: self.time,
+            player: self.player.map(owned),
+            champion: self.champion.map(owned),
+            text: owned(self.text),
+            source: self.source,
+        }
+    }
+}
+
+/// Where a record came from: the 1-based input line number and the byte
+/// range of the trimmed line text within the original input.
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
+pub struct SourceSpan {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl SourceSpan {
+    /// The original line text this span points at.
+    pub fn text<'a>(&self, input: &'a str) -> Option<&'a str> {
+        input.get(self.start..self.end)
+    }
+}
+
+/// Writes `{"line", "start", "end"}`; [`OutputOptions`] can leave the span
+/// out of its record.
+impl Serialize for SourceSpan {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Fields<'a>(&'a SourceSpan);
+
+        impl Serialize for Fields<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut span = serializer.serialize_struct("SourceSpan", 3)?;
+                span.serialize_field("line", &self.0.line)?;
+                span.serialize_field("start", &self.0.start)?;
+                span.serialize_field("end", &self.0.end)?;
+                span.end()
             }
-        })
-        .collect();
+        }
+
+        serializer.serialize_newtype_struct(output::SOURCE_SPAN, &Fields(self))
+    }
+}
+
+/// Everything parsed from one log. Text fields borrow from the input where
+/// they can; [`ParsedLog::into_owned`] detaches the result from it.
+///
+/// Deserializing always copies the text, so stored output loads into a
+/// `ParsedLog<'static>`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ParsedLog<'a> {
+    /// Layout version of the serialized log, see [`SCHEMA_VERSION`]. Output
+    /// written before the field existed loads as version 1.
+    #[serde(
+        default = "schema::first_version",
+        deserialize_with = "schema::deserialize_version"
+    )]
+    #[schemars(range(min = 1, max = SCHEMA_VERSION))]
+    pub schema_version: u32,
+    pub players: Vec<PlayerSummary<'a>>,
+    pub kills: Vec<KillEvent<'a>>,
+    pub events: Vec<ObjectiveEvent<'a>>,
+    pub messages: Vec<ChatMessage<'a>>,
+    pub system: Vec<SystemLine<'a>>,
+    pub unclassified: Vec<UnclassifiedLine<'a>>,
+    /// Every record above in original input order.
+    pub timeline: Vec<TimelineRef>,
+    /// Lines that were not, or only partly, recognized.
+    pub diagnostics: Vec<Diagnostic<'a>>,
+}
+
+impl Default for ParsedLog<'_> {
+    fn default() -> Self {
+        ParsedLog {
+            schema_version: SCHEMA_VERSION,
+            players: Vec::new(),
+            kills: Vec::new(),
+            events: Vec::new(),
+            messages: Vec::new(),
+            system: Vec::new(),
+            unclassified: Vec::new(),
+            timeline: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    Message,
+    Kill,
+    Event,
+    System,
+    Unclassified,
+}
 
-    players_vec.sort_by(|a, b| a.name.cmp(&b.name));
+/// Points at one record of a [`ParsedLog`]: `index` is the position inside
+/// the vector selected by `kind`, `line` is the 1-based input line number.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TimelineRef {
+    pub line: usize,
+    pub kind: RecordKind,
+    pub index: usize,
+}
+
+/// Borrowed typed payload of a timeline entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Record<'a> {
+    Message(&'a ChatMessage<'a>),
+    Kill(&'a KillEvent<'a>),
+    Event(&'a ObjectiveEvent<'a>),
+    System(&'a SystemLine<'a>),
+    Unclassified(&'a UnclassifiedLine<'a>),
+}
+
+impl<'a> Record<'a> {
+    pub fn kind(&self) -> RecordKind {
+        match self {
+            Record::Message(_) => RecordKind::Message,
+            Record::Kill(_) => RecordKind::Kill,
+            Record::Event(_) => RecordKind::Event,
+            Record::System(_) => RecordKind::System,
+            Record::Unclassified(_) => RecordKind::Unclassified,
+        }
+    }
 
-    ParsedLog {
-        players: players_vec,
-        kills,
-        events,
-        messages,
-        system,
+    /// Game time of the record; system lines carry no timestamp.
+    pub fn time(&self) -> Option<GameTime> {
+        match *self {
+            Record::Message(m) => Some(m.time),
+            Record::Kill(k) => Some(k.time),
+            Record::Event(e) => Some(e.time),
+            Record::Unclassified(u) => Some(u.time),
+            Record::System(_) => None,
+        }
     }
 }
 
-fn add_player(players: &mut HashMap<String, HashSet<String>>, player: &str, champion: &str) {
-    players
-        .entry(player.to_string())
-        .or_insert_with(HashSet::new)
-        .insert(champion.to_string());
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineEntry<'a> {
+    pub line: usize,
+    pub record: Record<'a>,
 }
 
-fn parse_chat_message(time: &str, rest: &str) -> Option<ChatMessage> {
-    let trimmed = rest.trim_start();
+impl<'a> ParsedLog<'a> {
+    /// Iterates every parsed record in the order it appeared in the input.
+    pub fn timeline(&self) -> impl Iterator<Item = TimelineEntry<'_>> + '_ {
+        self.timeline.iter().filter_map(|r| {
+            Some(TimelineEntry {
+                line: r.line,
+                record: self.record(r)?,
+            })
+        })
+    }
 
-    let (channel, after_channel) = if trimmed.starts_with('[') {
-        let closing = trimmed.find(']')?;
-        let tag = &trimmed[1..closing];
-        let channel = match tag {
-            "All" => ChatChannel::All,
-            "Team" => ChatChannel::Team,
-            "Party" => ChatChannel::Party,
-            _ => return None,
+    /// Appends the record of one streamed line, keeping `timeline` in input
+    /// order.
+    fn push(&mut self, source: SourceSpan, record: LogRecord<'a>) {
+        let (kind, index) = match record {
+            LogRecord::Message(chat) => {
+                self.messages.push(chat);
+                (RecordKind::Message, self.messages.len() - 1)
+            }
+            LogRecord::Kill(kill) => {
+                self.kills.push(kill);
+                (RecordKind::Kill, self.kills.len() - 1)
+            }
+            LogRecord::Event(event) => {
+                self.events.push(event);
+                (RecordKind::Event, self.events.len() - 1)
+            }
+            LogRecord::System(line) => {
+                self.system.push(line);
+                (RecordKind::System, self.system.len() - 1)
+            }
+            LogRecord::Unclassified(line) => {
+                self.unclassified.push(line);
+                (RecordKind::Unclassified, self.unclassified.len() - 1)
+            }
         };
-        let remainder = trimmed[closing + 1..].trim_start();
-        (channel, remainder)
+
+        self.timeline.push(TimelineRef {
+            line: source.line,
+            kind,
+            index,
+        });
+    }
+
+    /// Fails if any line produced a warning or an error diagnostic, see
+    /// [`parse_log_strict`].
+    pub fn into_strict(self) -> Result<ParsedLog<'a>, ParseErrors> {
+        let diagnostics: Vec<Diagnostic<'static>> = self
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity >= Severity::Warning)
+            .map(|d| d.clone().into_owned())
+            .collect();
+
+        if diagnostics.is_empty() {
+            Ok(self)
+        } else {
+            Err(ParseErrors { diagnostics })
+        }
+    }
+
+    /// Copies every borrowed field so the log outlives its input.
+    pub fn into_owned(self) -> ParsedLog<'static> {
+        ParsedLog {
+            schema_version: self.schema_version,
+            players: self
+                .players
+                .into_iter()
+                .map(PlayerSummary::into_owned)
+                .collect(),
+            kills: self.kills.into_iter().map(KillEvent::into_owned).collect(),
+            events: self
+                .events
+                .into_iter()
+                .map(ObjectiveEvent::into_owned)
+                .collect(),
+            messages: self
+                .messages
+                .into_iter()
+                .map(ChatMessage::into_owned)
+                .collect(),
+            system: self
+                .system
+                .into_iter()
+                .map(SystemLine::into_owned)
+                .collect(),
+            unclassified: self
+                .unclassified
+                .into_iter()
+                .map(UnclassifiedLine::into_owned)
+                .collect(),
+            timeline: self.timeline,
+            diagnostics: self
+                .diagnostics
+                .into_iter()
+                .map(Diagnostic::into_owned)
+                .collect(),
+        }
+    }
+
+    /// Resolves a [`TimelineRef`] to the record it points at.
+    pub fn record(&self, r: &TimelineRef) -> Option<Record<'_>> {
+        match r.kind {
+            RecordKind::Message => self.messages.get(r.index).map(Record::Message),
+            RecordKind::Kill => self.kills.get(r.index).map(Record::Kill),
+            RecordKind::Event => self.events.get(r.index).map(Record::Event),
+            RecordKind::System => self.system.get(r.index).map(Record::System),
+            RecordKind::Unclassified => self.unclassified.get(r.index).map(Record::Unclassified),
+        }
+    }
+}
+
+pub fn parse_timestamp(line: &str) -> Result<GameTime> {
+    let line_pair = parse_line(line).map_err(|e| anyhow!("Parse error for `line`: {e}"))?;
+    let time_pair = line_pair
+        .into_inner()
+        .next()
+        .ok_or_else(|| anyhow!("No `time` pair inside `line`"))?;
+    debug_assert_eq!(time_pair.as_rule(), Rule::time);
+
+    time_pair.as_str().parse()
+}
+
+fn parse_line(line: &str) -> Result<Pair<'_, Rule>, Box<pest::error::Error<Rule>>> {
+    let mut pairs = LolChatParser::parse(Rule::line, line).map_err(Box::new)?;
+    Ok(pairs
+        .next()
+        .expect("a successful `line` parse yields one pair"))
+}
+
+/// Like [`parse_log`], but fails if any line produced a warning or an error
+/// diagnostic, so new or broken message formats are not silently dropped.
+pub fn parse_log_strict(input: &str) -> Result<ParsedLog<'_>, ParseErrors> {
+    parse_log(input).into_strict()
+}
+
+/// Parses a log, detecting the client language as it goes: the first line
+/// only a built-in [`Locale`] can read fixes the language, see
+/// [`LogParser::new`].
+pub fn parse_log(input: &str) -> ParsedLog<'_> {
+    parse_with(input, LogParser::new())
+}
+
+/// Parses a log written by a client in the given language. Lines the
+/// English grammar already recognizes are never rewritten, so English lines
+/// in a mixed log still parse; rewritten lines own their text.
+pub fn parse_log_with_locale<'a>(input: &'a str, locale: &Locale) -> ParsedLog<'a> {
+    parse_with(input, LogParser::with_locale(*locale))
+}
+
+fn parse_with<'a>(input: &'a str, mut parser: LogParser<'a>) -> ParsedLog<'a> {
+    let mut log = ParsedLog::default();
+
+    for line in input.split_inclusive('\n') {
+        if let Some((source, record)) = parser.feed(line, &mut log.diagnostics) {
+            log.push(source, record);
+        }
+    }
+
+    log.players = parser.into_players();
+    log
+}
+
+/// Whether a `line` parse matched a specific rule rather than a fallback.
+fn is_recognized(line_pair: &Pair<'_, Rule>) -> bool {
+    line_pair
+        .clone()
+        .into_inner()
+        .nth(1)
+        .and_then(|body| body.into_inner().last())
+        .is_some_and(|event| {
+            !matches!(
+                event.as_rule(),
+                Rule::generic_player_event | Rule::generic_text
+            )
+        })
+}
+
+/// Describes a line the `line` rule rejected. Lines starting with a digit
+/// look like a timestamp, so losing them is an error; anything else is
+/// treated as an informational system line.
+fn rejected_line<'t>(
+    source: SourceSpan,
+    text: &'t str,
+    err: &pest::error::Error<Rule>,
+) -> Diagnostic<'t> {
+    let severity = if text.starts_with(|c: char| c.is_ascii_digit()) {
+        Severity::Error
     } else {
-        (ChatChannel::Player, trimmed)
+        Severity::Info
     };
 
-    let (player, champion, after_pc) = parse_player_with_champion_prefix(after_channel)?;
-    let after_pc_trimmed = after_pc.trim_start();
+    Diagnostic {
+        severity,
+        line: source.line,
+        rule: Rule::line,
+        message: Cow::Owned(err.variant.message().into_owned()),
+        text: Cow::Borrowed(text),
+    }
+}
+
+/// Player names seen so far, each with the set of champions they played.
+type Players<'a> = HashMap<Cow<'a, str>, HashSet<Cow<'a, str>>>;
+
+/// Registers every `player (champion)` pair a record mentions. Champions
+/// are stored in their canonical spelling; champions missing from the
+/// built-in table are kept as written and reported the first time a player
+/// is seen with them.
+fn register_players<'a>(
+    players: &mut Players<'a>,
+    record: &LogRecord<'a>,
+    mut report: impl FnMut(Severity, Rule, Cow<'static, str>),
+) {
+    let pairs = match record {
+        LogRecord::Message(chat) => [Some((&chat.player, &chat.champion)), None],
+        LogRecord::Kill(kill) => [
+            kill.killer.as_ref().zip(kill.killer_champion.as_ref()),
+            kill.victim.as_ref().zip(kill.victim_champion.as_ref()),
+        ],
+        LogRecord::Event(event) => [event.event.actor_names(), event.event.subject_names()],
+        LogRecord::Unclassified(line) => [line.player.as_ref().zip(line.champion.as_ref()), None],
+        LogRecord::System(_) => [None, None],
+    };
+
+    for (player, champion) in pairs.into_iter().flatten() {
+        let (champion, known) = match canonical_champion(champion) {
+            Some(canonical) => (Cow::Borrowed(canonical), true),
+            None => (champion.clone(), false),
+        };
 
-    if !after_pc_trimmed.starts_with(':') {
-        return None;
+        let new = match players.get_mut(player.as_ref()) {
+            Some(champions) if champions.contains(champion.as_ref()) => false,
+            Some(champions) => champions.insert(champion.clone()),
+            None => {
+                players.insert(player.clone(), HashSet::from([champion.clone()]));
+                true
+            }
+        };
+        // Reported once per player, however often the line repeats them.
+        if new && !known {
+            report(
+                Severity::Info,
+                Rule::champion_name,
+                format!("unknown champion `{champion}`").into(),
+            );
+        }
     }
-    let message = after_pc_trimmed[1..].trim_start();
+}
 
-    Some(ChatMessage {
-        time: time.to_string(),
-        channel,
-        player,
-        champion,
-        text: message.to_string(),
-    })
+/// Walks a matched `line` pair and builds the record for its `line_body`
+/// alternative, reporting anything it could not fully recognize. `text` is
+/// the original line, which differs from the parsed input when it was
+/// localized. Lines whose values cannot be converted are kept as system or
+/// unclassified lines, so every input line ends up in the result.
+fn build_record<'i, 't>(
+    line_pair: Pair<'i, Rule>,
+    text: &'t str,
+    source: SourceSpan,
+    diagnostics: &mut Vec<Diagnostic<'t>>,
+) -> LogRecord<'i> {
+    let mut report = |severity, rule, message: Cow<'static, str>| {
+        diagnostics.push(Diagnostic {
+            severity,
+            line: source.line,
+            rule,
+            message,
+            text: Cow::Borrowed(text),
+        });
+    };
+    // Without a usable timestamp the line can only be kept as written.
+    let system_line = || {
+        LogRecord::System(SystemLine {
+            text: Cow::Owned(text.to_string()),
+            source,
+        })
+    };
+
+    let mut inner = line_pair.into_inner();
+    let (Some(time_pair), Some(body_pair)) = (inner.next(), inner.next()) else {
+        report(
+            Severity::Error,
+            Rule::line,
+            "missing `time` or `line_body`".into(),
+        );
+        return system_line();
+    };
+    let time = match time_pair.as_str().parse::<GameTime>() {
+        Ok(time) => time,
+        Err(err) => {
+            report(Severity::Error, Rule::time, err.to_string().into());
+            return system_line();
+        }
+    };
+    // Text after the timestamp, for lines whose values are rejected.
+    let unclassified = |body: &'i str| {
+        LogRecord::Unclassified(UnclassifiedLine {
+            time,
+            player: None,
+            champion: None,
+            text: Cow::Borrowed(body),
+            source,
+        })
+    };
+
+    let body = body_pair.as_str();
+    // The actor leading the line, if any, comes before the event rule.
+    let mut inner = body_pair.into_inner();
+    let (actor, event_pair) = match (inner.next(), inner.next()) {
+        (Some(actor), Some(event_pair)) => (Some(actor), event_pair),
+        (Some(event_pair), None) => (None, event_pair),
+        _ => {
+            report(
+                Severity::Error,
+                Rule::line_body,
+                "no `line_body` alternative matched".into(),
+            );
+            return unclassified(body);
+        }
+    };
+
+    let rule = event_pair.as_rule();
+    let text_after_actor = event_pair.as_str();
+    // Builders see the actor as if the event rule had matched it.
+    let pairs = actor.into_iter().chain(event_pair.into_inner());
+
+    match rule {
+        Rule::channel_chat_message | Rule::bare_chat_message => {
+            LogRecord::Message(build_chat_message(time, pairs, source))
+        }
+        Rule::kill_shutdown_event
+        | Rule::kill_first_blood_event
+        | Rule::kill_slain_event
+        | Rule::kill_execution_event => {
+            let kill = build_kill_event(time, rule, pairs, source);
+            if kill.bounty_status == BountyStatus::Unparseable {
+                report(
+                    Severity::Warning,
+                    Rule::shutdown_bounty,
+                    "bounty amount could not be read".into(),
+                );
+            }
+            LogRecord::Kill(kill)
+        }
+        Rule::objective_slain_event
+        | Rule::monster_slain_event
+        | Rule::structure_destroyed_event
+        | Rule::structure_lost_event
+        | Rule::inhibitor_respawn_event
+        | Rule::ace_scored_event
+        | Rule::ace_event
+        | Rule::ace_suffered_event
+        | Rule::team_feat_event
+        | Rule::purchase_event
+        | Rule::target_player_event
+        | Rule::target_objective_event
+        | Rule::multikill_event
+        | Rule::streak_event
+        | Rule::streak_ended_event
+        | Rule::ping_on_the_way_event => {
+            let Some(event) = build_log_event(rule, pairs) else {
+                report(
+                    Severity::Error,
+                    rule,
+                    "matched but its values could not be converted".into(),
+                );
+                return unclassified(body);
+            };
+            LogRecord::Event(ObjectiveEvent {
+                time,
+                event,
+                source,
+            })
+        }
+        Rule::generic_player_event => {
+            report(
+                Severity::Warning,
+                rule,
+                "no specific event rule matched".into(),
+            );
+            LogRecord::Unclassified(build_unclassified(time, pairs, text_after_actor, source))
+        }
+        _ => {
+            report(Severity::Warning, rule, "no event rule matched".into());
+            LogRecord::Unclassified(build_unclassified(time, pairs, text_after_actor, source))
+        }
+    }
 }
 
-fn parse_player_with_champion_prefix(text: &str) -> Option<(String, String, &str)> {
-    let trimmed = text.trim_start();
+/// Keeps a `generic_player_event` / `generic_text` line, splitting off the
+/// acting player when the fallback rule found one.
+fn build_unclassified<'i>(
+    time: GameTime,
+    pairs: impl Iterator<Item = Pair<'i, Rule>>,
+    mut text: &'i str,
+    source: SourceSpan,
+) -> UnclassifiedLine<'i> {
+    let mut actor = None;
 
-    let open = trimmed.find('(')?;
-    let close_rel = trimmed[open..].find(')')?;
-    let close = open + close_rel;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::player_with_champion => actor = Some(player_with_champion(pair)),
+            Rule::generic_tail => text = pair.as_str(),
+            _ => {}
+        }
+    }
 
-    let player = trimmed[..open].trim();
-    if player.is_empty() {
-        return None;
+    UnclassifiedLine {
+        time,
+        player: actor.map(|(player, _)| Cow::Borrowed(player)),
+        champion: actor.map(|(_, champion)| Cow::Borrowed(champion)),
+        text: Cow::Borrowed(text),
+        source,
     }
+}
 
-    let champion = trimmed[open + 1..close].trim();
-    if champion.is_empty() {
-        return None;
+/// Splits a `player_with_champion` pair into `(player_name, champion_name)`.
+fn player_with_champion(pair: Pair<'_, Rule>) -> (&str, &str) {
+    let mut player = "";
+    let mut champion = "";
+
+    for inner in pair.into_inner() {
+        match inner.as_rule() {
+            Rule::player_name => player = inner.as_str(),
+            Rule::champion_name => champion = inner.as_str(),
+            _ => {}
+        }
     }
 
-    let remainder = &trimmed[close + 1..];
-    Some((player.to_string(), champion.to_string(), remainder))
+    (player, champion)
 }
 
-fn parse_target_player(rest: &str) -> Option<(String, String)> {
-    let text = rest.trim();
+fn build_chat_message<'i>(
+    time: GameTime,
+    pairs: impl Iterator<Item = Pair<'i, Rule>>,
+    source: SourceSpan,
+) -> ChatMessage<'i> {
+    let mut channel = ChatChannel::Player;
+    let mut player = "";
+    let mut champion = "";
+    let mut text = "";
 
-    let idx = text.find(" has targeted ")?;
-    let after = &text[idx + " has targeted ".len()..];
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::channel_tag => {
+                channel = match pair.as_str() {
+                    "[All]" => ChatChannel::All,
+                    "[Team]" => ChatChannel::Team,
+                    "[Party]" => ChatChannel::Party,
+                    _ => ChatChannel::Player,
+                };
+            }
+            Rule::player_with_champion => (player, champion) = player_with_champion(pair),
+            Rule::chat_text => text = pair.as_str(),
+            _ => {}
+        }
+    }
 
-    let dash_idx = after.find(" - (")?;
-    let target_name = after[..dash_idx].trim();
-    if target_name.is_empty() {
-        return None;
+    ChatMessage {
+        time,
+        channel,
+        player: Cow::Borrowed(player),
+        champion: Cow::Borrowed(champion),
+        text: Cow::Borrowed(text),
+        source,
     }
+}
+
+fn build_kill_event<'i>(
+    time: GameTime,
+    rule: Rule,
+    pairs: impl Iterator<Item = Pair<'i, Rule>>,
+    source: SourceSpan,
+) -> KillEvent<'i> {
+    let mut champions = Vec::with_capacity(2);
+    let mut non_champion = None;
+    let mut bounty = None;
+    let mut bounty_text = None;
+    let mut bounty_status = BountyStatus::Absent;
 
-    let after_dash = &after[dash_idx + " - (".len()..];
-    let close_paren = after_dash.find(')')?;
-    let champion = after_dash[..close_paren].trim();
-    if champion.is_empty() {
-        return None;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::player_with_champion => champions.push(player_with_champion(pair)),
+            Rule::non_champion_killer => non_champion = Some(non_champion_killer(pair)),
+            Rule::shutdown_bounty => {
+                let section = pair.into_inner().next();
+                bounty_text = section.as_ref().map(|p| Cow::Borrowed(p.as_str()));
+                bounty = section.and_then(bounty_amount);
+                bounty_status = if bounty.is_some() {
+                    BountyStatus::Parsed
+                } else {
+                    BountyStatus::Unparseable
+                };
+            }
+            _ => {}
+        }
     }
 
-    Some((target_name.to_string(), champion.to_string()))
+    let is_execution = rule == Rule::kill_execution_event;
+    let mut champions = champions.into_iter();
+    let (killer, killer_kind, victim) = match non_champion {
+        Some((name, kind)) => (Some((name, None)), kind, champions.next()),
+        None if is_execution => (None, KillerKind::Unknown, champions.next()),
+        None => {
+            let killer = champions.next().map(|(name, champ)| (name, Some(champ)));
+            (killer, KillerKind::Champion, champions.next())
+        }
+    };
+
+    KillEvent {
+        time,
+        killer: killer.map(|(name, _)| Cow::Borrowed(name)),
+        killer_champion: killer.and_then(|(_, champ)| champ).map(Cow::Borrowed),
+        killer_kind,
+        victim: victim.map(|(name, _)| Cow::Borrowed(name)),
+        victim_champion: victim.map(|(_, champ)| Cow::Borrowed(champ)),
+        bounty,
+        bounty_text,
+        bounty_status,
+        is_shutdown: rule == Rule::kill_shutdown_event,
+        is_first_blood: rule == Rule::kill_first_blood_event,
+        is_execution,
+        source,
+    }
 }
 
-fn parse_kill_event(time: &str, rest: &str) -> Option<KillEvent> {
-    let text = rest.trim();
+/// Reads the gold amount of a `bounty_value`, ignoring thousands
+/// separators; `bounty_unparsed` sections yield `None`.
+fn bounty_amount(section: Pair<'_, Rule>) -> Option<u32> {
+    let amount = section
+        .into_inner()
+        .find(|p| p.as_rule() == Rule::bounty_amount)?;
+    let digits: String = amount
+        .as_str()
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
 
-    if let Some(idx) = text.find(" has shut down ") {
-        let (killer_part, tail) = text.split_at(idx);
-        let tail = &tail[" has shut down ".len()..];
+/// Splits a `non_champion_killer` pair into its log text and kind.
+fn non_champion_killer(pair: Pair<'_, Rule>) -> (&str, KillerKind) {
+    let text = pair.as_str();
+    let kind = match pair.into_inner().next().map(|p| p.as_rule()) {
+        Some(Rule::killer_turret) => KillerKind::Turret,
+        Some(Rule::killer_minion) => KillerKind::Minion,
+        Some(Rule::killer_monster) => KillerKind::Monster,
+        _ => KillerKind::Unknown,
+    };
+    (text, kind)
+}
 
-        let (killer, killer_champ, _) = parse_player_with_champion_prefix(killer_part)?;
+/// Builds the typed [`LogEvent`] for one of the non-kill event rules from
+/// the pairs of the actor and the event.
+fn build_log_event<'i>(
+    rule: Rule,
+    pairs: impl Iterator<Item = Pair<'i, Rule>>,
+) -> Option<LogEvent<'i>> {
+    match rule {
+        Rule::objective_slain_event
+        | Rule::monster_slain_event
+        | Rule::structure_destroyed_event
+        | Rule::structure_lost_event
+        | Rule::inhibitor_respawn_event
+        | Rule::ace_scored_event
+        | Rule::ace_event
+        | Rule::ace_suffered_event => return build_objective_event(rule, pairs),
+        _ => {}
+    }
 
-        let exclam = tail.find('!')?;
-        let victim_part = &tail[..exclam];
-        let bonus_part = &tail[exclam + 1..];
+    let mut actor = None;
+    let mut team = None;
+    let mut phrase = "";
+    let mut target = "";
+    let mut target_champion = "";
+    let mut subject = None;
+    let mut percent = None;
+    let mut ping = None;
+    let mut level = None;
 
-        let (victim, victim_champ, _) = parse_player_with_champion_prefix(victim_part)?;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::player_with_champion if actor.is_none() => {
+                actor = Some(player_with_champion(pair));
+            }
+            Rule::player_with_champion => subject = Some(player_with_champion(pair)),
+            Rule::team_name => team = parse_team(pair.as_str()),
+            Rule::name_phrase => phrase = pair.as_str(),
+            Rule::player_name => target = pair.as_str(),
+            Rule::champion_name => target_champion = pair.as_str(),
+            Rule::percentage => percent = pair.as_str().parse::<u32>().ok(),
+            Rule::ping_phrase => ping = parse_ping_kind(pair.as_str()),
+            Rule::multikill | Rule::streak_level => level = parse_streak_level(pair.as_str()),
+            _ => {}
+        }
+    }
 
-        let bonus_start = bonus_part.find("Bonus Bounty:")?;
-        let bonus_text = &bonus_part[bonus_start + "Bonus Bounty:".len()..];
-        let bonus_text = bonus_text.trim();
-        let digits: String = bonus_text
-            .chars()
-            .take_while(|c| c.is_ascii_digit())
-            .collect();
-        let bounty = digits.parse::<u32>().ok();
-
-        return Some(KillEvent {
-            time: time.to_string(),
-            killer,
-            killer_champion: killer_champ,
-            victim: Some(victim),
-            victim_champion: Some(victim_champ),
-            bounty,
-            is_shutdown: true,
-            is_first_blood: false,
+    if rule == Rule::team_feat_event {
+        return Some(LogEvent::TeamFeat {
+            team: team?,
+            feat: Cow::Borrowed(phrase),
         });
     }
 
-    if let Some(idx) = text.find(" has drawn first blood!") {
-        let (killer_part, _) = text.split_at(idx);
-        let (killer, killer_champ, _) = parse_player_with_champion_prefix(killer_part)?;
+    let (player, champion) = actor?;
+    let (player, champion) = (Cow::Borrowed(player), Cow::Borrowed(champion));
 
-        return Some(KillEvent {
-            time: time.to_string(),
-            killer,
-            killer_champion: killer_champ,
-            victim: None,
-            victim_champion: None,
-            bounty: None,
-            is_shutdown: false,
-            is_first_blood: true,
-        });
+    let event = match rule {
+        Rule::purchase_event => LogEvent::Purchase {
+            player,
+            champion,
+            item: Cow::Borrowed(phrase),
+        },
+        Rule::ping_on_the_way_event => LogEvent::Ping {
+            player,
+            champion,
+            kind: ping?,
+        },
+        Rule::multikill_event | Rule::streak_event => LogEvent::Streak {
+            player,
+            champion,
+            level: level?,
+        },
+        Rule::streak_ended_event => {
+            let (victim, victim_champion) = subject?;
+            LogEvent::StreakEnded {
+                player,
+                champion,
+                victim: Cow::Borrowed(victim),
+                victim_champion: Cow::Borrowed(victim_champion),
+                level,
+            }
+        }
+        Rule::target_player_event => LogEvent::TargetPlayer {
+            player,
+            champion,
+            target: Cow::Borrowed(target),
+            target_champion: Cow::Borrowed(target_champion),
+        },
+        Rule::target_objective_event => LogEvent::TargetObjective {
+            player,
+            champion,
+            objective: Cow::Borrowed(phrase),
+            percent: percent?,
+        },
+        _ => return None,
+    };
+
+    Some(event)
+}
+
+/// Builds [`LogEvent::Objective`] from one of the objective rules.
+fn build_objective_event<'i>(
+    rule: Rule,
+    pairs: impl Iterator<Item = Pair<'i, Rule>>,
+) -> Option<LogEvent<'i>> {
+    let mut team = None;
+    let mut actor = None;
+    let mut owner = None;
+    let mut objective = None;
+
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::team_name => team = parse_team(pair.as_str()),
+            Rule::player_with_champion => actor = Some(player_with_champion(pair)),
+            Rule::article => owner = article_team(pair.as_str()),
+            Rule::epic_monster => objective = epic_monster(pair),
+            Rule::structure => {
+                objective = match pair.into_inner().next()?.as_rule() {
+                    Rule::nexus_turret => Some(Objective::NexusTurretDestroyed),
+                    Rule::inhibitor => Some(Objective::InhibitorDestroyed),
+                    _ => Some(Objective::TurretDestroyed),
+                };
+            }
+            _ => {}
+        }
     }
 
-    None
+    let (team, objective) = match rule {
+        // The team named on the line, or else the side opposite the
+        // structure's owner: "an enemy turret" fell to the ally team.
+        Rule::structure_lost_event | Rule::structure_destroyed_event => {
+            (team.or(owner.map(Team::opponent)), objective?)
+        }
+        Rule::inhibitor_respawn_event => (owner, Objective::InhibitorRespawned),
+        Rule::ace_scored_event | Rule::ace_event => (team, Objective::Ace),
+        Rule::ace_suffered_event => (team.map(Team::opponent), Objective::Ace),
+        _ => (team, objective?),
+    };
+
+    Some(LogEvent::Objective {
+        team,
+        player: actor.map(|(player, _)| Cow::Borrowed(player)),
+        champion: actor.map(|(_, champion)| Cow::Borrowed(champion)),
+        objective,
+    })
 }
 
-fn parse_objective_event(time: &str, rest: &str) -> Option<ObjectiveEvent> {
-    let text = rest.trim();
+fn epic_monster(pair: Pair<'_, Rule>) -> Option<Objective> {
+    let monster = pair.into_inner().next()?;
+    let objective = match monster.as_rule() {
+        Rule::elder_dragon => Objective::ElderDragon,
+        Rule::dragon => Objective::Dragon {
+            element: monster
+                .into_inner()
+                .next()
+                .and_then(|p| parse_dragon_element(p.as_str())),
+        },
+        Rule::baron_nashor => Objective::BaronNashor,
+        Rule::rift_herald => Objective::RiftHerald,
+        Rule::void_grubs => Objective::VoidGrubs,
+        Rule::atakhan => Objective::Atakhan,
+        _ => return None,
+    };
+    Some(objective)
+}
 
-    if text.contains(" has completed the ") {
-        let team_opt = if text.starts_with("Enemy team") {
-            Some("Enemy team".to_string())
-        } else if text.starts_with("Ally team") {
-            Some("Ally team".to_string())
-        } else if text.starts_with("Blue team") {
-            Some("Blue team".to_string())
-        } else if text.starts_with("Red team") {
-            Some("Red team".to_string())
-        } else {
-            None
-        };
+fn parse_dragon_element(text: &str) -> Option<DragonElement> {
+    match text.to_ascii_lowercase().as_str() {
+        "infernal" => Some(DragonElement::Infernal),
+        "mountain" => Some(DragonElement::Mountain),
+        "ocean" => Some(DragonElement::Ocean),
+        "cloud" => Some(DragonElement::Cloud),
+        "hextech" => Some(DragonElement::Hextech),
+        "chemtech" => Some(DragonElement::Chemtech),
+        _ => None,
+    }
+}
 
-        return Some(ObjectiveEvent {
-            time: time.to_string(),
-            team: team_opt,
-            description: text.to_string(),
-        });
+/// Side named by an `article`, e.g. "An enemy" or "Your"; plain articles
+/// name no side.
+fn article_team(text: &str) -> Option<Team> {
+    let text = text.to_ascii_lowercase();
+    if text.ends_with("enemy") {
+        Some(Team::Enemy)
+    } else if text.ends_with("allied") || text == "ally" || text == "your" {
+        Some(Team::Ally)
+    } else {
+        None
     }
+}
 
-    if text.contains("has targeted")
-        || text.contains("purchased ")
-        || text.contains("is on rampage!")
-        || text.contains(" is on the way")
-        || text.contains(" is missing")
-        || text.contains(" is retreating")
-        || text.contains(" is in danger")
-        || text.contains(" needs vision")
-    {
-        return Some(ObjectiveEvent {
-            time: time.to_string(),
-            team: None,
-            description: text.to_string(),
-        });
+fn parse_team(text: &str) -> Option<Team> {
+    match text {
+        "Enemy team" => Some(Team::Enemy),
+        "Ally team" | "Allied team" | "Your team" => Some(Team::Ally),
+        "Blue team" => Some(Team::Blue),
+        "Red team" => Some(Team::Red),
+        _ => None,
     }
+}
+
+/// Maps `multikill` / `streak_level` text, which the grammar matches
+/// case-insensitively.
+fn parse_streak_level(text: &str) -> Option<StreakLevel> {
+    match text.to_ascii_lowercase().as_str() {
+        "double kill" => Some(StreakLevel::DoubleKill),
+        "triple kill" => Some(StreakLevel::TripleKill),
+        "quadra kill" => Some(StreakLevel::QuadraKill),
+        "penta kill" | "pentakill" => Some(StreakLevel::PentaKill),
+        "killing spree" => Some(StreakLevel::KillingSpree),
+        "rampage" => Some(StreakLevel::Rampage),
+        "unstoppable" => Some(StreakLevel::Unstoppable),
+        "dominating" => Some(StreakLevel::Dominating),
+        "godlike" => Some(StreakLevel::Godlike),
+        "legendary" => Some(StreakLevel::Legendary),
+        _ => None,
+    }
+}
+
+fn parse_ping_kind(text: &str) -> Option<PingKind> {
+    match text {
+        "is on the way" => Some(PingKind::OnTheWay),
+        "is missing" => Some(PingKind::Missing),
+        "is retreating" => Some(PingKind::Retreating),
+        "is in danger" => Some(PingKind::InDanger),
+        "needs vision" => Some(PingKind::NeedsVision),
+        _ => None,
+    }
+}
 
-    None
+/// Detaches borrowed text from its input.
+fn owned(text: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(text.into_owned())
 }
@@ -0,0 +1,418 @@
+/// Phrase table for one client language.
+///
+/// The grammar only knows the English client, so localized lines are
+/// rewritten to their English form before parsing: a leading channel tag is
+/// swapped, or the whole line body is matched against `templates`, where
+/// `{}` captures any non-empty text. Captured values that equal a `terms`
+/// entry (ignoring case) are replaced too; everything else, such as player
+/// names and chat text, is kept as written.
+///
+/// The built-in Ukrainian, Polish and German tables were written by hand
+/// from the English announcements; they are not extracted from the client's
+/// string files and are not pinned to a client version. Each has a fixture
+/// in `tests/fixtures/locale_<code>.txt` that uses every entry. A phrase the
+/// client words differently leaves its line unclassified, with a
+/// diagnostic.
+///
+/// Tables are plain data, so a caller can build a [`Locale`] for another
+/// language and pass it to [`parse_log_with_locale`](crate::parse_log_with_locale).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// Short language code, e.g. `"uk"`.
+    pub code: &'static str,
+    pub name: &'static str,
+    /// `(localized tag, English tag)`, e.g. `("[Команда]", "[Team]")`.
+    pub channels: &'static [(&'static str, &'static str)],
+    /// `(localized template, English template)` with the same `{}` count,
+    /// tried in order.
+    pub templates: &'static [(&'static str, &'static str)],
+    /// `(localized value, English value)` for captured objectives, killers,
+    /// teams and announcements.
+    pub terms: &'static [(&'static str, &'static str)],
+}
+
+impl Locale {
+    /// The client language the grammar is written for; nothing is rewritten.
+    pub const ENGLISH: Locale = Locale {
+        code: "en",
+        name: "English",
+        channels: &[],
+        templates: &[],
+        terms: &[],
+    };
+
+    pub const UKRAINIAN: Locale = Locale {
+        code: "uk",
+        name: "Українська",
+        channels: &[
+            ("[Усі]", "[All]"),
+            ("[Всі]", "[All]"),
+            ("[Команда]", "[Team]"),
+            ("[Група]", "[Party]"),
+        ],
+        templates: &[
+            ("{} проливає першу кров!", "{} has drawn first blood!"),
+            ("{} зупиняє {}! {}", "{} has shut down {}! {}"),
+            ("{} зупиняє {}!", "{} has shut down {}!"),
+            ("{} вбиває {}!", "{} has slain {}!"),
+            ("{} страчено!", "{} has been executed!"),
+            ("{} страчено: {}!", "{} has been executed by {}!"),
+            ("{} купує {}", "{} purchased {}"),
+            ("{} націлюється на {} ({}%)", "{} has targeted {} ({}%)"),
+            ("{} націлюється на {}", "{} has targeted {}"),
+            ("{} руйнує {}!", "{} has destroyed {}!"),
+            ("{} зруйновано!", "{} has been destroyed!"),
+            ("{} відроджується!", "{} has respawned!"),
+            ("{} виконує {}!", "{} has completed the {}!"),
+            ("{} робить ейс!", "{} has scored an Ace!"),
+            ("{} отримує ейс!", "{} has been aced!"),
+            ("Ейс!", "Ace!"),
+            ("{} робить {}!", "{} got a {}!"),
+            ("{} влаштовує серію вбивств!", "{} is on a killing spree!"),
+            ("{} лютує!", "{} is on rampage!"),
+            ("{} нестримний!", "{} is unstoppable!"),
+            ("{} домінує!", "{} is dominating!"),
+            ("{} богоподібний!", "{} is godlike!"),
+            ("{} легендарний!", "{} is legendary!"),
+            ("{} перериває серію {}!", "{} has ended {}'s streak!"),
+            ("{} вже в дорозі", "{} is on the way"),
+            ("{} зник", "{} is missing"),
+            ("{} відступає", "{} is retreating"),
+            ("{} у небезпеці", "{} is in danger"),
+            ("{} потребує огляду", "{} needs vision"),
+        ],
+        terms: &[
+            ("Ворожа команда", "Enemy team"),
+            ("Союзна команда", "Ally team"),
+            ("Ваша команда", "Your team"),
+            ("Синя команда", "Blue team"),
+            ("Червона команда", "Red team"),
+            ("Подвиг війни", "Feat of Warfare"),
+            ("Квітку сили", "the Power Flower"),
+            ("Ворожа башта", "An enemy turret"),
+            ("ворожу башту", "an enemy turret"),
+            ("Ваша башта", "Your turret"),
+            ("Башта", "A turret"),
+            ("ворожий інгібітор", "an enemy inhibitor"),
+            ("Ворожий інгібітор", "Enemy inhibitor"),
+            ("Ваш інгібітор", "Your inhibitor"),
+            ("Посіпаки", "Minions"),
+            ("Монстр", "A monster"),
+            ("Барона Нашора", "Baron Nashor"),
+            ("Герольда Розлому", "Rift Herald"),
+            ("Старшого дракона", "Elder Dragon"),
+            ("Пекельного дракона", "Infernal Drake"),
+            ("Гірського дракона", "Mountain Drake"),
+            ("Океанічного дракона", "Ocean Drake"),
+            ("Хмарного дракона", "Cloud Drake"),
+            ("Хекстекового дракона", "Hextech Drake"),
+            ("Хемтекового дракона", "Chemtech Drake"),
+            ("Безодніх личинок", "Void Grubs"),
+            ("Атакана", "Atakhan"),
+            ("подвійне вбивство", "double kill"),
+            ("потрійне вбивство", "triple kill"),
+            ("четверне вбивство", "quadra kill"),
+            ("пентакіл", "pentakill"),
+        ],
+    };
+
+    pub const POLISH: Locale = Locale {
+        code: "pl",
+        name: "Polski",
+        channels: &[
+            ("[Wszyscy]", "[All]"),
+            ("[Drużyna]", "[Team]"),
+            ("[Grupa]", "[Party]"),
+        ],
+        templates: &[
+            ("{} przelewa pierwszą krew!", "{} has drawn first blood!"),
+            ("{} powstrzymuje {}! {}", "{} has shut down {}! {}"),
+            ("{} powstrzymuje {}!", "{} has shut down {}!"),
+            ("{} zabija {}!", "{} has slain {}!"),
+            ("{} został stracony!", "{} has been executed!"),
+            ("{} została stracona!", "{} has been executed!"),
+            ("{} kupuje {}", "{} purchased {}"),
+            ("{} celuje w {} ({}%)", "{} has targeted {} ({}%)"),
+            ("{} celuje w {}", "{} has targeted {}"),
+            ("{} niszczy {}!", "{} has destroyed {}!"),
+            ("{} zniszczona!", "{} has been destroyed!"),
+            ("{} zniszczony!", "{} has been destroyed!"),
+            ("{} odrodził się!", "{} has respawned!"),
+            ("{} ukończyła {}!", "{} has completed the {}!"),
+            ("{} zdobywa asa!", "{} has scored an Ace!"),
+            ("As!", "Ace!"),
+            ("{} zdobywa {}!", "{} got a {}!"),
+            ("{} ma serię zabójstw!", "{} is on a killing spree!"),
+            ("{} szaleje!", "{} is on rampage!"),
+            ("{} jest nie do zatrzymania!", "{} is unstoppable!"),
+            ("{} dominuje!", "{} is dominating!"),
+            ("{} jest jak bóg!", "{} is godlike!"),
+            ("{} jest legendarny!", "{} is legendary!"),
+            ("{} kończy serię {}!", "{} has ended {}'s streak!"),
+            ("{} jest w drodze", "{} is on the way"),
+            ("{} zaginął", "{} is missing"),
+            ("{} wycofuje się", "{} is retreating"),
+            ("{} jest w niebezpieczeństwie", "{} is in danger"),
+            ("{} potrzebuje wizji", "{} needs vision"),
+        ],
+        terms: &[
+            ("Drużyna wroga", "Enemy team"),
+            ("Drużyna sojusznicza", "Ally team"),
+            ("Twoja drużyna", "Your team"),
+            ("Niebieska drużyna", "Blue team"),
+            ("Czerwona drużyna", "Red team"),
+            ("Wyczyn Wojenny", "Feat of Warfare"),
+            ("Kwiat Mocy", "the Power Flower"),
+            ("Wroga wieża", "An enemy turret"),
+            ("wrogą wieżę", "an enemy turret"),
+            ("Twoja wieża", "Your turret"),
+            ("Wieża", "A turret"),
+            ("wrogi inhibitor", "an enemy inhibitor"),
+            ("Wrogi inhibitor", "Enemy inhibitor"),
+            ("Twój inhibitor", "Your inhibitor"),
+            ("Stwory", "Minions"),
+            ("Potwór", "A monster"),
+            ("Barona Nashora", "Baron Nashor"),
+            ("Herolda Szczeliny", "Rift Herald"),
+            ("Starszego Smoka", "Elder Dragon"),
+            ("Piekielnego Smoka", "Infernal Drake"),
+            ("Górskiego Smoka", "Mountain Drake"),
+            ("Oceanicznego Smoka", "Ocean Drake"),
+            ("Chmurnego Smoka", "Cloud Drake"),
+            ("Hextechowego Smoka", "Hextech Drake"),
+            ("Chemtechowego Smoka", "Chemtech Drake"),
+            ("Larwy Otchłani", "Void Grubs"),
+            ("Atakhana", "Atakhan"),
+            ("podwójne zabójstwo", "double kill"),
+            ("potrójne zabójstwo", "triple kill"),
+            ("poczwórne zabójstwo", "quadra kill"),
+            ("pentakill", "pentakill"),
+        ],
+    };
+
+    pub const GERMAN: Locale = Locale {
+        code: "de",
+        name: "Deutsch",
+        channels: &[
+            ("[Alle]", "[All]"),
+            ("[Team]", "[Team]"),
+            ("[Gruppe]", "[Party]"),
+        ],
+        templates: &[
+            (
+                "{} hat das erste Blut vergossen!",
+                "{} has drawn first blood!",
+            ),
+            ("{} hat {} ausgeschaltet! {}", "{} has shut down {}! {}"),
+            ("{} hat {} ausgeschaltet!", "{} has shut down {}!"),
+            ("{} hat {} getötet!", "{} has slain {}!"),
+            ("{} wurde hingerichtet!", "{} has been executed!"),
+            (
+                "{} wurde von {} hingerichtet!",
+                "{} has been executed by {}!",
+            ),
+            ("{} hat {} gekauft", "{} purchased {}"),
+            ("{} hat {} ({}%) anvisiert", "{} has targeted {} ({}%)"),
+            ("{} hat {} anvisiert", "{} has targeted {}"),
+            ("{} hat {} zerstört!", "{} has destroyed {}!"),
+            ("{} wurde zerstört!", "{} has been destroyed!"),
+            ("{} ist wieder da!", "{} has respawned!"),
+            ("{} hat die {} abgeschlossen!", "{} has completed the {}!"),
+            ("{} hat ein Ass erzielt!", "{} has scored an Ace!"),
+            ("{} wurde ausgelöscht!", "{} has been aced!"),
+            ("Ass!", "Ace!"),
+            ("{} hat einen {} erzielt!", "{} has scored a {}!"),
+            ("{} ist im Blutrausch!", "{} is on a killing spree!"),
+            ("{} wütet!", "{} is on rampage!"),
+            ("{} ist unaufhaltsam!", "{} is unstoppable!"),
+            ("{} dominiert!", "{} is dominating!"),
+            ("{} ist gottgleich!", "{} is godlike!"),
+            ("{} ist legendär!", "{} is legendary!"),
+            (
+                "{} hat die Serie von {} beendet!",
+                "{} has ended {}'s streak!",
+            ),
+            ("{} ist unterwegs", "{} is on the way"),
+            ("{} wird vermisst", "{} is missing"),
+            ("{} zieht sich zurück", "{} is retreating"),
+            ("{} ist in Gefahr", "{} is in danger"),
+            ("{} braucht Sicht", "{} needs vision"),
+        ],
+        terms: &[
+            ("Gegnerisches Team", "Enemy team"),
+            ("Verbündetes Team", "Ally team"),
+            ("Dein Team", "Your team"),
+            ("Blaues Team", "Blue team"),
+            ("Rotes Team", "Red team"),
+            ("Heldentat der Kriegsführung", "Feat of Warfare"),
+            ("die Kraftblume", "the Power Flower"),
+            ("Ein gegnerischer Turm", "An enemy turret"),
+            ("einen gegnerischen Turm", "an enemy turret"),
+            ("Dein Turm", "Your turret"),
+            ("Ein Turm", "A turret"),
+            ("einen gegnerischen Inhibitor", "an enemy inhibitor"),
+            ("Gegnerischer Inhibitor", "Enemy inhibitor"),
+            ("Dein Inhibitor", "Your inhibitor"),
+            ("Vasallen", "Minions"),
+            ("Ein Monster", "A monster"),
+            ("den Baron Nashor", "Baron Nashor"),
+            ("den Herold der Kluft", "Rift Herald"),
+            ("den Älteren Drachen", "Elder Dragon"),
+            ("den Infernodrachen", "Infernal Drake"),
+            ("den Bergdrachen", "Mountain Drake"),
+            ("den Ozeandrachen", "Ocean Drake"),
+            ("den Wolkendrachen", "Cloud Drake"),
+            ("den Hextech-Drachen", "Hextech Drake"),
+            ("den Chemtech-Drachen", "Chemtech Drake"),
+            ("die Leerenlarven", "Void Grubs"),
+            ("Doppelkill", "double kill"),
+            ("Dreifachkill", "triple kill"),
+            ("Vierfachkill", "quadra kill"),
+            ("Pentakill", "pentakill"),
+        ],
+    };
+
+    /// Every built-in locale, English first.
+    pub const ALL: &'static [&'static Locale] = &[
+        &Locale::ENGLISH,
+        &Locale::UKRAINIAN,
+        &Locale::POLISH,
+        &Locale::GERMAN,
+    ];
+
+    /// Looks up a built-in locale by its [`code`](Locale::code).
+    pub fn from_code(code: &str) -> Option<&'static Locale> {
+        Locale::ALL
+            .iter()
+            .copied()
+            .find(|locale| locale.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Picks the built-in locale whose phrases match the most lines of
+    /// `input`, falling back to English when no localized phrase is found.
+    pub fn detect(input: &str) -> &'static Locale {
+        let mut best = (&Locale::ENGLISH, 0);
+
+        for &locale in &Locale::ALL[1..] {
+            let hits = input
+                .lines()
+                .filter_map(|line| line.trim().split_once(' '))
+                .filter(|(_, body)| locale.recognizes(body))
+                .count();
+            if hits > best.1 {
+                best = (locale, hits);
+            }
+        }
+
+        best.0
+    }
+
+    /// English renderings of a line body (the text after the timestamp),
+    /// most specific first. Empty when nothing in the table matches.
+    pub fn translate(&self, body: &str) -> Vec<String> {
+        if let Some(english) = self.translate_channel(body) {
+            return vec![english];
+        }
+
+        self.templates
+            .iter()
+            .filter_map(|(localized, english)| {
+                let captures = match_template(localized, body)?;
+                Some(fill_template(
+                    english,
+                    captures.into_iter().map(|value| self.term(value)),
+                ))
+            })
+            .collect()
+    }
+
+    /// Whether `body` uses a phrase specific to this locale.
+    fn recognizes(&self, body: &str) -> bool {
+        self.channels
+            .iter()
+            .any(|(localized, english)| localized != english && starts_with_tag(body, localized))
+            || self
+                .templates
+                .iter()
+                .any(|(localized, _)| match_template(localized, body).is_some())
+    }
+
+    fn translate_channel(&self, body: &str) -> Option<String> {
+        self.channels.iter().find_map(|(localized, english)| {
+            starts_with_tag(body, localized)
+                .then(|| format!("{english}{}", &body[localized.len()..]))
+        })
+    }
+
+    fn term<'a>(&self, value: &'a str) -> &'a str {
+        let lowercase = value.to_lowercase();
+        self.terms
+            .iter()
+            .find(|(localized, _)| *localized == value)
+            .or_else(|| {
+                self.terms
+                    .iter()
+                    .find(|(localized, _)| localized.to_lowercase() == lowercase)
+            })
+            .map_or(value, |(_, english)| english)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::ENGLISH
+    }
+}
+
+fn starts_with_tag(body: &str, tag: &str) -> bool {
+    body.strip_prefix(tag)
+        .is_some_and(|rest| rest.starts_with(' '))
+}
+
+/// Matches `text` against a template whose `{}` placeholders each capture
+/// the shortest non-empty text before the next literal; the last literal
+/// must end the text.
+fn match_template<'a>(template: &str, text: &'a str) -> Option<Vec<&'a str>> {
+    let mut literals = template.split("{}").peekable();
+    let first = literals.next()?;
+    // Most templates fail on their fixed ends; check those before allocating.
+    let last = template.rsplit("{}").next()?;
+    if !text.ends_with(last) {
+        return None;
+    }
+    let mut rest = text.strip_prefix(first)?;
+    let mut captures = Vec::new();
+
+    while let Some(literal) = literals.next() {
+        let end = if literals.peek().is_none() {
+            let end = rest.len().checked_sub(literal.len())?;
+            (rest.get(end..)? == literal).then_some(end)?
+        } else {
+            // Skip at least one character so the capture is never empty.
+            let skip = rest.chars().next()?.len_utf8();
+            skip + rest[skip..].find(literal)?
+        };
+        if end == 0 {
+            return None;
+        }
+        captures.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    rest.is_empty().then_some(captures)
+}
+
+fn fill_template<'a>(template: &str, mut values: impl Iterator<Item = &'a str>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut parts = template.split("{}");
+
+    if let Some(first) = parts.next() {
+        out.push_str(first);
+    }
+    for part in parts {
+        out.push_str(values.next().unwrap_or_default());
+        out.push_str(part);
+    }
+
+    out
+}
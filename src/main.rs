@@ -1,10 +1,16 @@
 use std::env;
 use std::fs;
+use std::path::Path;
 use std::process;
+use std::thread;
+use std::time::Duration;
 
 use anyhow::Result;
-use lol_chat_parser::parse_log;
-use serde_json::to_string_pretty;
+use lol_chat_parser::{
+    json_schema, parse_log, parse_log_with_locale, player_stats, render_html, render_report,
+    to_csv_string, to_json_string, to_json_string_pretty, to_ndjson_string, CsvTable, JsonLine,
+    Locale, LogFollower, LogParser, OutputOptions, ParsedLog, ReportFormat, TimeFormat,
+};
 
 fn main() {
     if let Err(err) = run() {
@@ -21,7 +27,39 @@ fn run() -> Result<()> {
             let path = args
                 .next()
                 .ok_or_else(|| anyhow::anyhow!("missing file path for `parse` command"))?;
-            parse_command(&path)?;
+            let options = parse_command_options(args)?;
+            parse_command(&path, &options)?;
+        }
+        Some("watch") => {
+            let path = args
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("missing file path for `watch` command"))?;
+            let options = watch_command_options(args)?;
+            watch_command(&path, &options)?;
+        }
+        Some("report") => {
+            let path = args
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("missing file path for `report` command"))?;
+            let options = report_command_options(args)?;
+            report_command(&path, &options)?;
+        }
+        Some("html") => {
+            let path = args
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("missing file path for `html` command"))?;
+            let options = html_command_options(args)?;
+            html_command(&path, &options)?;
+        }
+        Some("stats") => {
+            let path = args
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("missing file path for `stats` command"))?;
+            let options = stats_command_options(args)?;
+            stats_command(&path, &options)?;
+        }
+        Some("schema") => {
+            println!("{}", serde_json::to_string_pretty(&json_schema())?);
         }
         Some("help") | None => {
             print_help();
@@ -38,14 +76,366 @@ fn run() -> Result<()> {
     Ok(())
 }
 
-fn parse_command(path: &str) -> Result<()> {
+/// Flags accepted after `parse <path>`.
+#[derive(Debug, Default)]
+struct ParseOptions {
+    output: OutputOptions,
+    strict: bool,
+    /// Client language; detected from the log when not given.
+    locale: Option<&'static Locale>,
+    format: OutputFormat,
+    /// The one CSV table to print; all of them are written to files otherwise.
+    table: Option<CsvTable>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    #[default]
+    Json,
+    /// One compact JSON object per line, as `watch` prints.
+    Ndjson,
+    Csv,
+}
+
+fn parse_command_options(mut args: impl Iterator<Item = String>) -> Result<ParseOptions> {
+    let mut options = ParseOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--time" => options.output.time_format = time_format_arg(args.next())?,
+            "--no-source" => options.output.include_source = false,
+            "--strict" => options.strict = true,
+            "--locale" => options.locale = locale_arg(args.next())?,
+            "--format" => options.format = format_arg(args.next())?,
+            "--table" => options.table = Some(table_arg(args.next())?),
+            other => return Err(anyhow::anyhow!("unknown option `{other}`")),
+        }
+    }
+
+    if options.table.is_some() && options.format != OutputFormat::Csv {
+        return Err(anyhow::anyhow!("`--table` requires `--format csv`"));
+    }
+    Ok(options)
+}
+
+/// Flags accepted after `watch <path>`.
+#[derive(Debug)]
+struct WatchOptions {
+    output: OutputOptions,
+    /// Client language; detected from the first localized line when not given.
+    locale: Option<&'static Locale>,
+    /// How long to wait for new lines before checking the file again.
+    interval: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        WatchOptions {
+            output: OutputOptions::default(),
+            locale: None,
+            interval: Duration::from_millis(250),
+        }
+    }
+}
+
+fn watch_command_options(mut args: impl Iterator<Item = String>) -> Result<WatchOptions> {
+    let mut options = WatchOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--time" => options.output.time_format = time_format_arg(args.next())?,
+            "--no-source" => options.output.include_source = false,
+            "--locale" => options.locale = locale_arg(args.next())?,
+            "--interval" => {
+                let millis = args
+                    .next()
+                    .and_then(|ms| ms.parse().ok())
+                    .ok_or_else(|| anyhow::anyhow!("`--interval` expects milliseconds"))?;
+                options.interval = Duration::from_millis(millis);
+            }
+            other => return Err(anyhow::anyhow!("unknown option `{other}`")),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Flags accepted after `report <path>`.
+#[derive(Debug)]
+struct ReportOptions {
+    format: ReportFormat,
+    strict: bool,
+    locale: Option<&'static Locale>,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        ReportOptions {
+            format: ReportFormat::Text,
+            strict: false,
+            locale: None,
+        }
+    }
+}
+
+fn report_command_options(mut args: impl Iterator<Item = String>) -> Result<ReportOptions> {
+    let mut options = ReportOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let value = args.next();
+                options.format = value
+                    .as_deref()
+                    .and_then(ReportFormat::from_name)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("`--format` expects `markdown` or `text`, got {value:?}")
+                    })?;
+            }
+            "--strict" => options.strict = true,
+            "--locale" => options.locale = locale_arg(args.next())?,
+            other => return Err(anyhow::anyhow!("unknown option `{other}`")),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Flags accepted after `html <path>`.
+#[derive(Debug, Default)]
+struct HtmlOptions {
+    /// Where to write the page; `<path stem>.html` next to the log otherwise.
+    output: Option<String>,
+    strict: bool,
+    locale: Option<&'static Locale>,
+}
+
+fn html_command_options(mut args: impl Iterator<Item = String>) -> Result<HtmlOptions> {
+    let mut options = HtmlOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => {
+                let file = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("`{arg}` expects a file path"))?;
+                options.output = Some(file);
+            }
+            "--strict" => options.strict = true,
+            "--locale" => options.locale = locale_arg(args.next())?,
+            other => return Err(anyhow::anyhow!("unknown option `{other}`")),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Flags accepted after `stats <path>`.
+#[derive(Debug, Default)]
+struct StatsOptions {
+    output: OutputOptions,
+    strict: bool,
+    locale: Option<&'static Locale>,
+}
+
+fn stats_command_options(mut args: impl Iterator<Item = String>) -> Result<StatsOptions> {
+    let mut options = StatsOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--time" => options.output.time_format = time_format_arg(args.next())?,
+            "--strict" => options.strict = true,
+            "--locale" => options.locale = locale_arg(args.next())?,
+            other => return Err(anyhow::anyhow!("unknown option `{other}`")),
+        }
+    }
+
+    Ok(options)
+}
+
+fn time_format_arg(value: Option<String>) -> Result<TimeFormat> {
+    match value.as_deref() {
+        Some("clock") => Ok(TimeFormat::Clock),
+        Some("seconds") => Ok(TimeFormat::Seconds),
+        other => Err(anyhow::anyhow!(
+            "`--time` expects `clock` or `seconds`, got {other:?}"
+        )),
+    }
+}
+
+fn format_arg(value: Option<String>) -> Result<OutputFormat> {
+    match value.as_deref() {
+        Some("json") => Ok(OutputFormat::Json),
+        Some("ndjson") => Ok(OutputFormat::Ndjson),
+        Some("csv") => Ok(OutputFormat::Csv),
+        other => Err(anyhow::anyhow!(
+            "`--format` expects `json`, `ndjson` or `csv`, got {other:?}"
+        )),
+    }
+}
+
+fn table_arg(value: Option<String>) -> Result<CsvTable> {
+    value
+        .as_deref()
+        .and_then(CsvTable::from_name)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "`--table` expects `messages`, `kills`, `events` or `players`, got {value:?}"
+            )
+        })
+}
+
+/// `None` means "detect from the log".
+fn locale_arg(value: Option<String>) -> Result<Option<&'static Locale>> {
+    match value.as_deref() {
+        Some("auto") => Ok(None),
+        Some(code) => Locale::from_code(code)
+            .map(Some)
+            .ok_or_else(|| anyhow::anyhow!("unknown locale `{code}`")),
+        None => Err(anyhow::anyhow!("`--locale` expects a language code")),
+    }
+}
+
+/// Parses a log read from disk in the given language, or detecting it when
+/// `locale` is `None`; `strict` fails on any warning or error.
+fn parse_content<'a>(
+    content: &'a str,
+    locale: Option<&Locale>,
+    strict: bool,
+) -> Result<ParsedLog<'a>> {
+    let parsed = match locale {
+        Some(locale) => parse_log_with_locale(content, locale),
+        None => parse_log(content),
+    };
+    if strict {
+        Ok(parsed.into_strict()?)
+    } else {
+        Ok(parsed)
+    }
+}
+
+fn parse_command(path: &str, options: &ParseOptions) -> Result<()> {
+    let content = fs::read_to_string(path)?;
+    let parsed = parse_content(&content, options.locale, options.strict)?;
+
+    match (options.format, options.table) {
+        (OutputFormat::Json, _) => {
+            let json = to_json_string_pretty(&parsed, options.output)?;
+            println!("{json}");
+        }
+        (OutputFormat::Ndjson, _) => {
+            for diagnostic in &parsed.diagnostics {
+                eprintln!("{diagnostic}");
+            }
+            print!("{}", to_ndjson_string(&parsed, options.output)?);
+        }
+        (OutputFormat::Csv, Some(table)) => {
+            print!("{}", to_csv_string(&parsed, table, options.output));
+        }
+        // One file per table next to the log, e.g. `game.kills.csv`. Nothing
+        // is written if any of them already exists.
+        (OutputFormat::Csv, None) => {
+            let csv_paths: Vec<_> = CsvTable::ALL
+                .iter()
+                .map(|table| {
+                    let csv_path = Path::new(path).with_extension(format!("{}.csv", table.name()));
+                    (*table, csv_path)
+                })
+                .collect();
+            let existing: Vec<_> = csv_paths
+                .iter()
+                .filter(|(_, csv_path)| csv_path.exists())
+                .map(|(_, csv_path)| csv_path.display().to_string())
+                .collect();
+            if !existing.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "refusing to overwrite {}; remove them or print one table with `--table`",
+                    existing.join(", ")
+                ));
+            }
+            for (table, csv_path) in csv_paths {
+                fs::write(&csv_path, to_csv_string(&parsed, table, options.output))?;
+                eprintln!("Wrote {}", csv_path.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn report_command(path: &str, options: &ReportOptions) -> Result<()> {
+    let content = fs::read_to_string(path)?;
+    let parsed = parse_content(&content, options.locale, options.strict)?;
+    print!("{}", render_report(&parsed, options.format));
+    Ok(())
+}
+
+fn html_command(path: &str, options: &HtmlOptions) -> Result<()> {
     let content = fs::read_to_string(path)?;
-    let parsed = parse_log(&content);
-    let json = to_string_pretty(&parsed)?;
+    let parsed = parse_content(&content, options.locale, options.strict)?;
+
+    let log_path = Path::new(path);
+    let title = match log_path.file_name() {
+        Some(name) => format!("Match – {}", name.to_string_lossy()),
+        None => "Match".to_string(),
+    };
+    // The page next to the log is never overwritten; an explicit `-o` is.
+    let html_path = match &options.output {
+        Some(file) => Path::new(file).to_path_buf(),
+        None => {
+            let html_path = log_path.with_extension("html");
+            if html_path.exists() {
+                return Err(anyhow::anyhow!(
+                    "refusing to overwrite {}; remove it or choose the output with `-o`",
+                    html_path.display()
+                ));
+            }
+            html_path
+        }
+    };
+    fs::write(&html_path, render_html(&parsed, &title)?)?;
+    eprintln!("Wrote {}", html_path.display());
+    Ok(())
+}
+
+fn stats_command(path: &str, options: &StatsOptions) -> Result<()> {
+    let content = fs::read_to_string(path)?;
+    let parsed = parse_content(&content, options.locale, options.strict)?;
+    let json = to_json_string_pretty(&player_stats(&parsed), options.output)?;
     println!("{json}");
     Ok(())
 }
 
+/// Tails `path` and prints one JSON record per line as lines appear, followed
+/// by a `player` line whenever a player or their champion is new; diagnostics
+/// go to stderr. Runs until interrupted.
+fn watch_command(path: &str, options: &WatchOptions) -> Result<()> {
+    let parser = match options.locale {
+        Some(locale) => LogParser::with_locale(*locale),
+        None => LogParser::new(),
+    };
+    let mut follower = LogFollower::open(path, parser)?;
+    let mut players = Vec::new();
+
+    loop {
+        for line in follower.poll()? {
+            for diagnostic in &line.diagnostics {
+                eprintln!("{diagnostic}");
+            }
+            println!("{}", to_json_string(&line.record, options.output)?);
+        }
+
+        let current = follower.parser().players();
+        for player in current.iter().filter(|p| !players.contains(*p)) {
+            println!(
+                "{}",
+                to_json_string(&JsonLine::Player(player), options.output)?
+            );
+        }
+        players = current;
+        thread::sleep(options.interval);
+    }
+}
+
 fn print_help() {
     println!(
         "\
@@ -56,8 +446,45 @@ USAGE:
 
 COMMANDS:
     parse <path>    Parse a text file with LoL chat logs and print structured JSON
+    watch <path>    Follow a growing log file and print each new record as a JSON line
+    report <path>   Print a match summary for the terminal or a Markdown wiki
+    html <path>     Write a self-contained HTML page with an interactive timeline
+    stats <path>    Print per-player counts (messages, shutdowns, pings, ...) as JSON
+    schema          Print the JSON Schema of the `parse` output
     help            Show this help information
     credits         Show project credits
+
+OPTIONS (parse):
+    --time <clock|seconds>    Write times as \"MM:SS\" (default) or seconds since game start
+    --no-source               Omit the line number and byte span of every record
+    --strict                  Fail if any line was not fully recognized
+    --locale <auto|en|uk|pl|de>
+                              Client language of the log (default: detected)
+    --format <json|ndjson|csv>
+                              Output format (default: json); `ndjson` prints one
+                              record per line in log order, then the players
+    --table <messages|kills|events|players>
+                              With `--format csv`, print only this table; otherwise
+                              every table is written to `<path stem>.<table>.csv`,
+                              failing if any of those files already exists
+
+OPTIONS (report):
+    --format <text|markdown>  Plain text with aligned columns (default) or Markdown
+    --strict, --locale        Same as for `parse`
+
+OPTIONS (html):
+    -o, --output <file>       Where to write the page (default: the log path with `.html`,
+                              which must not exist yet)
+    --strict, --locale        Same as for `parse`
+
+OPTIONS (stats):
+    --time, --strict, --locale
+                              Same as for `parse`
+
+OPTIONS (watch):
+    --time, --no-source, --locale
+                              Same as for `parse`
+    --interval <ms>           How often to check the file for new lines (default: 250)
 "
     );
 }
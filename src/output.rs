@@ -0,0 +1,623 @@
+use std::fmt::{self, Display};
+
+use serde::ser::{
+    self, Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{Serialize, Serializer};
+
+use crate::game_time::TimeFormat;
+use crate::{
+    ChatMessage, GameTime, KillEvent, ObjectiveEvent, ParsedLog, PlayerSummary, Record, SystemLine,
+    UnclassifiedLine,
+};
+
+/// Newtype name under which [`GameTime`] passes its clock text, so an
+/// [`OptionsSerializer`] can write seconds instead.
+pub(crate) const GAME_TIME: &str = "$lol_chat_parser::GameTime";
+/// Newtype name under which a [`SourceSpan`](crate::SourceSpan) passes its
+/// fields, so an [`OptionsSerializer`] can leave it out.
+pub(crate) const SOURCE_SPAN: &str = "$lol_chat_parser::SourceSpan";
+
+/// Knobs that change how parsed records are serialized.
+///
+/// The defaults reproduce the plain `#[derive(Serialize)]` output. Other
+/// settings apply to values serialized through [`OutputOptions::apply`] or
+/// the [`to_json_string`] helpers, on any thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputOptions {
+    pub time_format: TimeFormat,
+    /// Whether records keep their `source` line number and byte span.
+    pub include_source: bool,
+}
+
+impl OutputOptions {
+    pub const DEFAULT: OutputOptions = OutputOptions {
+        time_format: TimeFormat::Clock,
+        include_source: true,
+    };
+
+    /// Wraps `value` so it serializes with these options, with any
+    /// `serde` format:
+    ///
+    /// ```
+    /// use lol_chat_parser::{parse_log, OutputOptions, TimeFormat};
+    ///
+    /// let log = parse_log("13:10 BorysBulba (Tahm Kench) purchased Control Ward");
+    /// let options = OutputOptions {
+    ///     time_format: TimeFormat::Seconds,
+    ///     include_source: false,
+    /// };
+    /// let json = serde_json::to_value(options.apply(&log.events[0])).unwrap();
+    /// assert_eq!(json["time"], 790);
+    /// assert!(json.get("source").is_none());
+    /// ```
+    pub fn apply<T: Serialize + ?Sized>(self, value: &T) -> WithOptions<'_, T> {
+        WithOptions {
+            value,
+            options: self,
+        }
+    }
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        OutputOptions::DEFAULT
+    }
+}
+
+pub fn to_json_string<T: Serialize + ?Sized>(
+    value: &T,
+    options: OutputOptions,
+) -> serde_json::Result<String> {
+    serde_json::to_string(&options.apply(value))
+}
+
+pub fn to_json_string_pretty<T: Serialize + ?Sized>(
+    value: &T,
+    options: OutputOptions,
+) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&options.apply(value))
+}
+
+/// Writes `log` as JSON Lines: one compact [`JsonLine`] per timeline record in
+/// log order, then one per player. Diagnostics are left out.
+pub fn to_ndjson_string(log: &ParsedLog<'_>, options: OutputOptions) -> serde_json::Result<String> {
+    let mut out = String::new();
+    let records = log.timeline().map(|entry| JsonLine::from(entry.record));
+    for line in records.chain(log.players.iter().map(JsonLine::Player)) {
+        out.push_str(&to_json_string(&line, options)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+/// One line of JSON Lines output, as `{"kind": "player", "record": {...}}`.
+/// Records have the same layout as a serialized
+/// [`LogRecord`](crate::LogRecord).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "record", rename_all = "snake_case")]
+pub enum JsonLine<'a> {
+    Message(&'a ChatMessage<'a>),
+    Kill(&'a KillEvent<'a>),
+    Event(&'a ObjectiveEvent<'a>),
+    System(&'a SystemLine<'a>),
+    Unclassified(&'a UnclassifiedLine<'a>),
+    Player(&'a PlayerSummary<'a>),
+}
+
+impl<'a> From<Record<'a>> for JsonLine<'a> {
+    fn from(record: Record<'a>) -> Self {
+        match record {
+            Record::Message(m) => JsonLine::Message(m),
+            Record::Kill(k) => JsonLine::Kill(k),
+            Record::Event(e) => JsonLine::Event(e),
+            Record::System(s) => JsonLine::System(s),
+            Record::Unclassified(u) => JsonLine::Unclassified(u),
+        }
+    }
+}
+
+/// A value serialized with [`OutputOptions`], see [`OutputOptions::apply`].
+#[derive(Debug, Clone, Copy)]
+pub struct WithOptions<'a, T: ?Sized> {
+    value: &'a T,
+    options: OutputOptions,
+}
+
+impl<T: Serialize + ?Sized> Serialize for WithOptions<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(OptionsSerializer {
+            inner: serializer,
+            options: self.options,
+        })
+    }
+}
+
+/// Forwards everything to `inner`, passing the options down to nested
+/// values, and rewrites the [`GAME_TIME`] and [`SOURCE_SPAN`] newtypes.
+struct OptionsSerializer<S> {
+    inner: S,
+    options: OutputOptions,
+}
+
+impl<S> OptionsSerializer<S> {
+    fn wrap<'v, T: ?Sized>(&self, value: &'v T) -> WithOptions<'v, T> {
+        WithOptions {
+            value,
+            options: self.options,
+        }
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<S::Ok, S::Error> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for OptionsSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_i64(v: i64);
+        serialize_i128(v: i128);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_u64(v: u64);
+        serialize_u128(v: u128);
+        serialize_f32(v: f32);
+        serialize_f64(v: f64);
+        serialize_char(v: char);
+        serialize_str(v: &str);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(name: &'static str, index: u32, variant: &'static str);
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_some(&value)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let probed = match name {
+            GAME_TIME if self.options.time_format == TimeFormat::Seconds => {
+                value.serialize(Probe).ok()
+            }
+            _ => None,
+        };
+        if let Some(Token::Clock(time)) = probed {
+            return self.inner.serialize_u32(time.as_seconds());
+        }
+        let value = self.wrap(value);
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = self.wrap(value);
+        self.inner
+            .serialize_newtype_variant(name, index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_seq(len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple(len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple_struct(name, len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_tuple_variant(name, index, variant, len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_map(len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_struct(name, len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_struct_variant(name, index, variant, len)?,
+            options: self.options,
+        })
+    }
+
+    fn collect_str<T: Display + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner.collect_str(value)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Serializer state for sequences, maps and structs of an
+/// [`OptionsSerializer`]; drops `source` fields when they are hidden.
+struct Compound<C> {
+    inner: C,
+    options: OutputOptions,
+}
+
+impl<C> Compound<C> {
+    fn wrap<'v, T: ?Sized>(&self, value: &'v T) -> WithOptions<'v, T> {
+        WithOptions {
+            value,
+            options: self.options,
+        }
+    }
+
+    fn is_hidden<T: Serialize + ?Sized>(&self, value: &T) -> bool {
+        !self.options.include_source
+            && matches!(value.serialize(Probe), Ok(Token::Named(SOURCE_SPAN)))
+    }
+}
+
+impl<C: SerializeSeq> SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// Flattened structs, such as [`ObjectiveEvent`], arrive as maps; their
+/// fields come through [`SerializeMap::serialize_entry`].
+impl<C: SerializeMap> SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), C::Error> {
+        let key = self.wrap(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn serialize_entry<K: Serialize + ?Sized, V: Serialize + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<(), C::Error> {
+        if self.is_hidden(value) {
+            return Ok(());
+        }
+        let (key, value) = (self.wrap(key), self.wrap(value));
+        self.inner.serialize_entry(&key, &value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStruct> SerializeStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        if self.is_hidden(value) {
+            return self.inner.skip_field(key);
+        }
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        if self.is_hidden(value) {
+            return self.inner.skip_field(key);
+        }
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// What [`Probe`] found out about a value without writing it.
+enum Token {
+    /// A newtype struct, by name.
+    Named(&'static str),
+    /// Clock text, as passed under [`GAME_TIME`].
+    Clock(GameTime),
+    Other,
+}
+
+/// Looks at the outermost shape of a value; containers are rejected
+/// straight away, so probing is cheap.
+struct Probe;
+
+#[derive(Debug)]
+struct NotProbed;
+
+impl Display for NotProbed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value is not a probed token")
+    }
+}
+
+impl std::error::Error for NotProbed {}
+
+impl ser::Error for NotProbed {
+    fn custom<T: Display>(_: T) -> Self {
+        NotProbed
+    }
+}
+
+macro_rules! other {
+    ($($method:ident($($ty:ty),*);)*) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<Token, NotProbed> {
+                Ok(Token::Other)
+            }
+        )*
+    };
+}
+
+impl Serializer for Probe {
+    type Ok = Token;
+    type Error = NotProbed;
+    type SerializeSeq = Impossible<Token, NotProbed>;
+    type SerializeTuple = Impossible<Token, NotProbed>;
+    type SerializeTupleStruct = Impossible<Token, NotProbed>;
+    type SerializeTupleVariant = Impossible<Token, NotProbed>;
+    type SerializeMap = Impossible<Token, NotProbed>;
+    type SerializeStruct = Impossible<Token, NotProbed>;
+    type SerializeStructVariant = Impossible<Token, NotProbed>;
+
+    other! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(&'static str);
+        serialize_unit_variant(&'static str, u32, &'static str);
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<Token, NotProbed> {
+        Ok(Token::Other)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _: &T,
+    ) -> Result<Token, NotProbed> {
+        Ok(Token::Named(name))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Token, NotProbed> {
+        Ok(Token::Other)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, NotProbed> {
+        Err(NotProbed)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, NotProbed> {
+        Err(NotProbed)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, NotProbed> {
+        Err(NotProbed)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, NotProbed> {
+        Err(NotProbed)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, NotProbed> {
+        Err(NotProbed)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, NotProbed> {
+        Err(NotProbed)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, NotProbed> {
+        Err(NotProbed)
+    }
+
+    /// [`GameTime`] writes its clock text this way.
+    fn collect_str<T: Display + ?Sized>(self, value: &T) -> Result<Token, NotProbed> {
+        value
+            .to_string()
+            .parse()
+            .map(Token::Clock)
+            .map_err(|_| NotProbed)
+    }
+}
@@ -0,0 +1,324 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::{
+    ChatChannel, ChatMessage, DragonElement, KillEvent, KillerKind, LogEvent, LogRecord, Objective,
+    ObjectiveEvent, ParsedLog, PingKind, Record, StreakLevel, SystemLine, Team, UnclassifiedLine,
+};
+
+/// Writes every record of `log` back as one log line, in timeline order.
+///
+/// Lines come out in one canonical English form per record, e.g. `Your team`
+/// becomes `Ally team`, times are written as `MM:SS` (`1:05:00` becomes
+/// `65:00`) and localized lines are translated, so rendering a parsed log
+/// also normalizes it. Parsing the result yields the same records, and a
+/// line already in canonical form renders back unchanged.
+pub fn render_log(log: &ParsedLog<'_>) -> String {
+    let mut out = String::new();
+    for entry in log.timeline() {
+        // Writing to a `String` cannot fail.
+        let _ = writeln!(out, "{}", entry.record);
+    }
+    out
+}
+
+impl Display for ChatMessage<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.time)?;
+        match self.channel {
+            ChatChannel::All => f.write_str("[All] ")?,
+            ChatChannel::Team => f.write_str("[Team] ")?,
+            ChatChannel::Party => f.write_str("[Party] ")?,
+            ChatChannel::Player => {}
+        }
+        write!(f, "{} ({}):", self.player, self.champion)?;
+        if !self.text.is_empty() {
+            write!(f, " {}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// The killer and victim keep their log text; the bounty section is written
+/// as it appeared, or as `Bonus Bounty` when only the amount is known.
+impl Display for KillEvent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.time)?;
+        let victim = self.victim.as_deref().unwrap_or_default();
+        let victim_champion = self.victim_champion.as_deref().unwrap_or_default();
+
+        if self.is_execution {
+            write!(f, "{victim} ({victim_champion}) has been executed")?;
+            if let Some(killer) = &self.killer {
+                write!(f, " by {killer}")?;
+            }
+            return f.write_str("!");
+        }
+
+        let killer = self.killer.as_deref().unwrap_or_default();
+        match &self.killer_champion {
+            Some(champion) => write!(f, "{killer} ({champion})")?,
+            None => f.write_str(killer)?,
+        }
+        if self.is_first_blood {
+            return f.write_str(" has drawn first blood!");
+        }
+
+        let verb = if self.is_shutdown {
+            "shut down"
+        } else {
+            "slain"
+        };
+        // "The enemy minions have slain ...", as the client writes it.
+        let has = if self.killer_kind == KillerKind::Minion && killer.ends_with(['s', 'S']) {
+            "have"
+        } else {
+            "has"
+        };
+        write!(f, " {has} {verb} {victim} ({victim_champion})!")?;
+        match (&self.bounty_text, self.bounty) {
+            (Some(text), _) => write!(f, " ({text})"),
+            (None, Some(gold)) => write!(f, " (Bonus Bounty: {gold}G)"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl Display for ObjectiveEvent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.time, self.event)
+    }
+}
+
+/// The line body after the timestamp.
+impl Display for LogEvent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LogEvent::Purchase {
+                player,
+                champion,
+                item,
+            } => write!(f, "{player} ({champion}) purchased {item}"),
+            LogEvent::Ping {
+                player,
+                champion,
+                kind,
+            } => write!(f, "{player} ({champion}) {}", ping_phrase(*kind)),
+            LogEvent::Streak {
+                player,
+                champion,
+                level,
+            } => {
+                write!(f, "{player} ({champion}) ")?;
+                match level {
+                    level if level.is_multikill() => write!(f, "got a {}!", streak_name(*level)),
+                    StreakLevel::KillingSpree => f.write_str("is on a killing spree!"),
+                    StreakLevel::Rampage => f.write_str("is on rampage!"),
+                    level => write!(f, "is {}!", streak_name(*level)),
+                }
+            }
+            LogEvent::StreakEnded {
+                player,
+                champion,
+                victim,
+                victim_champion,
+                level,
+            } => write!(
+                f,
+                "{player} ({champion}) has ended {victim} ({victim_champion})'s {}!",
+                level.map_or("streak", streak_name)
+            ),
+            LogEvent::TargetPlayer {
+                player,
+                champion,
+                target,
+                target_champion,
+            } => write!(
+                f,
+                "{player} ({champion}) has targeted {target} - ({target_champion})"
+            ),
+            LogEvent::TargetObjective {
+                player,
+                champion,
+                objective,
+                percent,
+            } => write!(
+                f,
+                "{player} ({champion}) has targeted the {objective} ({percent}%)"
+            ),
+            LogEvent::TeamFeat { team, feat } => {
+                write!(f, "{} has completed the {feat}!", team_name(*team))
+            }
+            LogEvent::Objective {
+                team,
+                player,
+                champion,
+                objective,
+            } => {
+                let player = player.as_deref().zip(champion.as_deref());
+                write_objective(f, player, *team, *objective)
+            }
+        }
+    }
+}
+
+/// Writes an objective line for the player or, failing that, the team that
+/// got it.
+fn write_objective(
+    f: &mut Formatter<'_>,
+    player: Option<(&str, &str)>,
+    team: Option<Team>,
+    objective: Objective,
+) -> fmt::Result {
+    match objective {
+        Objective::TurretDestroyed => write_structure(f, player, team, "a", "turret"),
+        Objective::InhibitorDestroyed => write_structure(f, player, team, "an", "inhibitor"),
+        Objective::NexusTurretDestroyed => write_structure(f, player, team, "a", "nexus turret"),
+        Objective::InhibitorRespawned => match team {
+            Some(Team::Enemy) => f.write_str("Enemy inhibitor has respawned!"),
+            Some(Team::Ally) => f.write_str("Your inhibitor has respawned!"),
+            _ => f.write_str("Inhibitor has respawned!"),
+        },
+        Objective::Ace => match (player, team) {
+            (Some((player, champion)), _) => write!(f, "{player} ({champion}) has scored an Ace!"),
+            (None, Some(team)) => write!(f, "{} has scored an Ace!", team_name(team)),
+            (None, None) => f.write_str("Ace!"),
+        },
+        Objective::Dragon { element } => write_monster(f, player, team, dragon_name(element)),
+        Objective::ElderDragon => write_monster(f, player, team, "Elder Dragon"),
+        Objective::BaronNashor => write_monster(f, player, team, "Baron Nashor"),
+        Objective::RiftHerald => write_monster(f, player, team, "Rift Herald"),
+        Objective::VoidGrubs => write_monster(f, player, team, "Void Grubs"),
+        Objective::Atakhan => write_monster(f, player, team, "Atakhan"),
+    }
+}
+
+/// `article` is the structure's indefinite article, "a" or "an".
+fn write_structure(
+    f: &mut Formatter<'_>,
+    player: Option<(&str, &str)>,
+    team: Option<Team>,
+    article: &str,
+    structure: &str,
+) -> fmt::Result {
+    match (player, team) {
+        (Some((player, champion)), Some(Team::Enemy)) => {
+            write!(f, "{player} ({champion}) has destroyed your {structure}!")
+        }
+        (Some((player, champion)), Some(_)) => write!(
+            f,
+            "{player} ({champion}) has destroyed an enemy {structure}!"
+        ),
+        (Some((player, champion)), None) => write!(
+            f,
+            "{player} ({champion}) has destroyed {article} {structure}!"
+        ),
+        (None, Some(team)) => write!(
+            f,
+            "{} has destroyed {article} {structure}!",
+            team_name(team)
+        ),
+        (None, None) => {
+            let article = if article == "an" { "An" } else { "A" };
+            write!(f, "{article} {structure} has been destroyed!")
+        }
+    }
+}
+
+fn write_monster(
+    f: &mut Formatter<'_>,
+    player: Option<(&str, &str)>,
+    team: Option<Team>,
+    monster: &str,
+) -> fmt::Result {
+    match (player, team) {
+        (Some((player, champion)), _) => write!(f, "{player} ({champion}) has slain {monster}!"),
+        (None, Some(team)) => write!(f, "{} has slain the {monster}!", team_name(team)),
+        (None, None) => write!(f, "The {monster} has been slain!"),
+    }
+}
+
+impl Display for SystemLine<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl Display for UnclassifiedLine<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.time)?;
+        if let (Some(player), Some(champion)) = (&self.player, &self.champion) {
+            write!(f, "{player} ({champion}) ")?;
+        }
+        f.write_str(&self.text)
+    }
+}
+
+impl Display for LogRecord<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LogRecord::Message(m) => m.fmt(f),
+            LogRecord::Kill(k) => k.fmt(f),
+            LogRecord::Event(e) => e.fmt(f),
+            LogRecord::System(s) => s.fmt(f),
+            LogRecord::Unclassified(u) => u.fmt(f),
+        }
+    }
+}
+
+impl Display for Record<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Record::Message(m) => m.fmt(f),
+            Record::Kill(k) => k.fmt(f),
+            Record::Event(e) => e.fmt(f),
+            Record::System(s) => s.fmt(f),
+            Record::Unclassified(u) => u.fmt(f),
+        }
+    }
+}
+
+fn team_name(team: Team) -> &'static str {
+    match team {
+        Team::Enemy => "Enemy team",
+        Team::Ally => "Ally team",
+        Team::Blue => "Blue team",
+        Team::Red => "Red team",
+    }
+}
+
+fn ping_phrase(kind: PingKind) -> &'static str {
+    match kind {
+        PingKind::OnTheWay => "is on the way",
+        PingKind::Missing => "is missing",
+        PingKind::Retreating => "is retreating",
+        PingKind::InDanger => "is in danger",
+        PingKind::NeedsVision => "needs vision",
+    }
+}
+
+fn streak_name(level: StreakLevel) -> &'static str {
+    match level {
+        StreakLevel::DoubleKill => "double kill",
+        StreakLevel::TripleKill => "triple kill",
+        StreakLevel::QuadraKill => "quadra kill",
+        StreakLevel::PentaKill => "pentakill",
+        StreakLevel::KillingSpree => "killing spree",
+        StreakLevel::Rampage => "rampage",
+        StreakLevel::Unstoppable => "unstoppable",
+        StreakLevel::Dominating => "dominating",
+        StreakLevel::Godlike => "godlike",
+        StreakLevel::Legendary => "legendary",
+    }
+}
+
+fn dragon_name(element: Option<DragonElement>) -> &'static str {
+    match element {
+        None => "Dragon",
+        Some(DragonElement::Infernal) => "Infernal Drake",
+        Some(DragonElement::Mountain) => "Mountain Drake",
+        Some(DragonElement::Ocean) => "Ocean Drake",
+        Some(DragonElement::Cloud) => "Cloud Drake",
+        Some(DragonElement::Hextech) => "Hextech Drake",
+        Some(DragonElement::Chemtech) => "Chemtech Drake",
+    }
+}
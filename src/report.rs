@@ -0,0 +1,291 @@
+use std::fmt::Write;
+
+use crate::{ChatChannel, ChatMessage, KillEvent, LogEvent, ParsedLog};
+
+/// How many chat lines [`render_report`] quotes.
+const QUOTES: usize = 5;
+
+/// Layout of a [`render_report`] summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// GitHub-flavored Markdown with one table per section, for wikis.
+    Markdown,
+    /// Aligned columns under underlined headings, for the terminal.
+    Text,
+}
+
+impl ReportFormat {
+    pub fn from_name(name: &str) -> Option<ReportFormat> {
+        match name.to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Some(ReportFormat::Markdown),
+            "text" | "txt" => Some(ReportFormat::Text),
+            _ => None,
+        }
+    }
+}
+
+/// Summarizes a match: roster, first blood, shutdowns with bounties, the
+/// objectives timeline, chat volume per channel and the longest chat lines
+/// as notable quotes.
+pub fn render_report(log: &ParsedLog<'_>, format: ReportFormat) -> String {
+    let sections = [
+        roster(log),
+        first_blood(log),
+        shutdowns(log),
+        objectives(log),
+        chat_volume(log),
+        quotes(log),
+    ];
+
+    let mut out = String::new();
+    match format {
+        ReportFormat::Markdown => out.push_str("# Match report\n\n"),
+        ReportFormat::Text => out.push_str("MATCH REPORT\n\n"),
+    }
+    out.push_str(&overview(log));
+    out.push('\n');
+    for section in &sections {
+        out.push('\n');
+        match format {
+            ReportFormat::Markdown => section.write_markdown(&mut out),
+            ReportFormat::Text => section.write_text(&mut out),
+        }
+    }
+    out
+}
+
+struct Section {
+    title: &'static str,
+    body: Body,
+}
+
+enum Body {
+    Table {
+        header: &'static [&'static str],
+        rows: Vec<Vec<String>>,
+    },
+    /// One entry per line, e.g. a quote.
+    Lines(Vec<String>),
+}
+
+impl Section {
+    fn table(title: &'static str, header: &'static [&'static str], rows: Vec<Vec<String>>) -> Self {
+        Section {
+            title,
+            body: Body::Table { header, rows },
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match &self.body {
+            Body::Table { rows, .. } => rows.is_empty(),
+            Body::Lines(lines) => lines.is_empty(),
+        }
+    }
+
+    fn write_markdown(&self, out: &mut String) {
+        // Writing to a `String` cannot fail.
+        let _ = writeln!(out, "## {}\n", self.title);
+        if self.is_empty() {
+            out.push_str("None.\n");
+            return;
+        }
+        match &self.body {
+            Body::Table { header, rows } => {
+                let _ = writeln!(out, "| {} |", header.join(" | "));
+                let _ = writeln!(out, "|{}", "---|".repeat(header.len()));
+                for row in rows {
+                    let cells: Vec<String> = row.iter().map(|cell| markdown_cell(cell)).collect();
+                    let _ = writeln!(out, "| {} |", cells.join(" | "));
+                }
+            }
+            Body::Lines(lines) => {
+                for line in lines {
+                    let _ = writeln!(out, "> {}\n", markdown_cell(line));
+                }
+                out.pop();
+            }
+        }
+    }
+
+    fn write_text(&self, out: &mut String) {
+        let _ = writeln!(out, "{}", self.title);
+        let _ = writeln!(out, "{}", "-".repeat(self.title.chars().count()));
+        if self.is_empty() {
+            out.push_str("None.\n");
+            return;
+        }
+        match &self.body {
+            Body::Table { header, rows } => {
+                let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+                for row in rows {
+                    for (width, cell) in widths.iter_mut().zip(row) {
+                        *width = (*width).max(cell.chars().count());
+                    }
+                }
+                let header: Vec<String> = header.iter().map(|h| h.to_string()).collect();
+                for row in std::iter::once(&header).chain(rows) {
+                    let mut line = String::new();
+                    for (cell, width) in row.iter().zip(&widths) {
+                        let _ = write!(line, "{cell:<width$}  ");
+                    }
+                    let _ = writeln!(out, "{}", line.trim_end());
+                }
+            }
+            Body::Lines(lines) => {
+                for line in lines {
+                    let _ = writeln!(out, "  {line}");
+                }
+            }
+        }
+    }
+}
+
+/// Game length and record counts.
+fn overview(log: &ParsedLog<'_>) -> String {
+    let length = log
+        .timeline()
+        .filter_map(|entry| entry.record.time())
+        .max()
+        .unwrap_or_default();
+    format!(
+        "Length {length}: {}, {}, {}.",
+        count(log.players.len(), "player"),
+        count(log.kills.len(), "kill"),
+        count(log.messages.len(), "chat message")
+    )
+}
+
+/// `1 kill`, `2 kills`.
+fn count(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("{n} {noun}")
+    } else {
+        format!("{n} {noun}s")
+    }
+}
+
+fn roster(log: &ParsedLog<'_>) -> Section {
+    let rows = log
+        .players
+        .iter()
+        .map(|p| vec![p.name.to_string(), p.champions.join(", ")])
+        .collect();
+    Section::table("Roster", &["Player", "Champions"], rows)
+}
+
+fn first_blood(log: &ParsedLog<'_>) -> Section {
+    let rows = log
+        .kills
+        .iter()
+        .filter(|k| k.is_first_blood)
+        .take(1)
+        .map(|k| vec![k.time.to_string(), killer(k), victim(k)])
+        .collect();
+    Section::table("First blood", &["Time", "Killer", "Victim"], rows)
+}
+
+fn shutdowns(log: &ParsedLog<'_>) -> Section {
+    let rows = log
+        .kills
+        .iter()
+        .filter(|k| k.is_shutdown)
+        .map(|k| {
+            let bounty = k.bounty.map(|gold| format!("{gold}G")).unwrap_or_default();
+            vec![k.time.to_string(), killer(k), victim(k), bounty]
+        })
+        .collect();
+    Section::table("Shutdowns", &["Time", "Killer", "Victim", "Bounty"], rows)
+}
+
+/// Objectives, structures, aces and team feats, in log order.
+fn objectives(log: &ParsedLog<'_>) -> Section {
+    let rows = log
+        .events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e.event,
+                LogEvent::Objective { .. } | LogEvent::TeamFeat { .. }
+            )
+        })
+        .map(|e| vec![e.time.to_string(), e.event.to_string()])
+        .collect();
+    Section::table("Objectives", &["Time", "Event"], rows)
+}
+
+fn chat_volume(log: &ParsedLog<'_>) -> Section {
+    let channels = [
+        (ChatChannel::All, "All"),
+        (ChatChannel::Team, "Team"),
+        (ChatChannel::Party, "Party"),
+        (ChatChannel::Player, "Player"),
+    ];
+    let rows = channels
+        .iter()
+        .filter_map(|&(channel, name)| {
+            let messages: Vec<&ChatMessage> = log
+                .messages
+                .iter()
+                .filter(|m| m.channel == channel)
+                .collect();
+            let mut players: Vec<&str> = messages.iter().map(|m| m.player.as_ref()).collect();
+            players.sort_unstable();
+            players.dedup();
+            (!messages.is_empty()).then(|| {
+                vec![
+                    name.to_string(),
+                    messages.len().to_string(),
+                    players.len().to_string(),
+                ]
+            })
+        })
+        .collect();
+    Section::table("Chat", &["Channel", "Messages", "Players"], rows)
+}
+
+/// The longest chat lines, in log order.
+fn quotes(log: &ParsedLog<'_>) -> Section {
+    let mut longest: Vec<(usize, &ChatMessage)> = log.messages.iter().enumerate().collect();
+    longest.sort_by_key(|(i, m)| (std::cmp::Reverse(m.text.chars().count()), *i));
+    longest.truncate(QUOTES);
+    longest.sort_by_key(|(i, _)| *i);
+
+    let lines = longest
+        .into_iter()
+        .map(|(_, m)| format!("\"{}\" - {} ({}), {}", m.text, m.player, m.champion, m.time))
+        .collect();
+    Section {
+        title: "Notable quotes",
+        body: Body::Lines(lines),
+    }
+}
+
+fn killer(kill: &KillEvent<'_>) -> String {
+    let name = kill.killer.as_deref().unwrap_or_default();
+    match &kill.killer_champion {
+        Some(champion) => format!("{name} ({champion})"),
+        None => name.to_string(),
+    }
+}
+
+/// Empty when the log line did not name the victim, as for first blood.
+fn victim(kill: &KillEvent<'_>) -> String {
+    match (&kill.victim, &kill.victim_champion) {
+        (Some(name), Some(champion)) => format!("{name} ({champion})"),
+        (Some(name), None) => name.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Keeps chat text from breaking out of a table cell or quote.
+fn markdown_cell(text: &str) -> String {
+    let mut cell = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '|' | '\\' | '*' | '_' | '`' | '<' | '>' | '[' | ']') {
+            cell.push('\\');
+        }
+        cell.push(c);
+    }
+    cell
+}
@@ -0,0 +1,38 @@
+use serde::{de, Deserialize, Deserializer};
+
+use crate::ParsedLog;
+
+/// Version of the JSON layout of a serialized [`ParsedLog`].
+///
+/// Bumped when a field is renamed or removed or changes meaning; adding an
+/// optional field keeps the version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// JSON Schema (draft 2020-12) of a serialized [`ParsedLog`] at
+/// [`SCHEMA_VERSION`], with times in either [`TimeFormat`](crate::TimeFormat).
+pub fn json_schema() -> serde_json::Value {
+    let mut schema = schemars::schema_for!(ParsedLog<'static>).to_value();
+    // Reading tolerates a missing version, but written output always has one.
+    if let Some(required) = schema["required"].as_array_mut() {
+        required.insert(0, "schema_version".into());
+    }
+    schema
+}
+
+pub(crate) fn first_version() -> u32 {
+    1
+}
+
+/// Rejects output from a newer layout that this version cannot read.
+pub(crate) fn deserialize_version<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<u32, D::Error> {
+    let version = u32::deserialize(deserializer)?;
+    if (1..=SCHEMA_VERSION).contains(&version) {
+        Ok(version)
+    } else {
+        Err(de::Error::custom(format_args!(
+            "unsupported schema_version {version}, expected 1 to {SCHEMA_VERSION}"
+        )))
+    }
+}
@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{owned, ChatChannel, GameTime, LogEvent, ParsedLog, PingKind};
+
+/// Counts for one player of a [`ParsedLog`], from [`player_stats`].
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PlayerStats<'a> {
+    pub name: Cow<'a, str>,
+    pub champions: Vec<Cow<'a, str>>,
+    pub messages: MessageCounts,
+    pub first_bloods: u32,
+    pub shutdowns_dealt: u32,
+    pub shutdowns_received: u32,
+    /// Bounty gold from the player's kills, as far as the log shows it.
+    pub bounty_gold: u32,
+    pub pings: PingCounts,
+    pub purchases: u32,
+    /// Players and map objectives this player targeted.
+    pub targets_made: u32,
+    /// Times another player targeted this one.
+    pub targets_received: u32,
+    /// Time of the first record naming the player, as actor or subject.
+    pub first_seen: Option<GameTime>,
+    pub last_seen: Option<GameTime>,
+}
+
+impl PlayerStats<'_> {
+    pub fn into_owned(self) -> PlayerStats<'static> {
+        PlayerStats {
+            name: owned(self.name),
+            champions: self.champions.into_iter().map(owned).collect(),
+            messages: self.messages,
+            first_bloods: self.first_bloods,
+            shutdowns_dealt: self.shutdowns_dealt,
+            shutdowns_received: self.shutdowns_received,
+            bounty_gold: self.bounty_gold,
+            pings: self.pings,
+            purchases: self.purchases,
+            targets_made: self.targets_made,
+            targets_received: self.targets_received,
+            first_seen: self.first_seen,
+            last_seen: self.last_seen,
+        }
+    }
+
+    /// Widens the activity window to include `time`.
+    fn seen(&mut self, time: GameTime) {
+        self.first_seen = Some(self.first_seen.map_or(time, |t| t.min(time)));
+        self.last_seen = Some(self.last_seen.map_or(time, |t| t.max(time)));
+    }
+}
+
+/// Chat messages sent per channel.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MessageCounts {
+    pub all: u32,
+    pub team: u32,
+    pub party: u32,
+    pub player: u32,
+}
+
+impl MessageCounts {
+    pub fn total(&self) -> u32 {
+        self.all + self.team + self.party + self.player
+    }
+}
+
+/// Smart pings sent per kind.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PingCounts {
+    pub on_the_way: u32,
+    pub missing: u32,
+    pub retreating: u32,
+    pub in_danger: u32,
+    pub needs_vision: u32,
+}
+
+impl PingCounts {
+    pub fn total(&self) -> u32 {
+        self.on_the_way + self.missing + self.retreating + self.in_danger + self.needs_vision
+    }
+}
+
+/// Per-player counts from the `messages`, `kills`, `events` and
+/// `unclassified` records of `log`, one entry per player in `log.players`
+/// order.
+pub fn player_stats<'a>(log: &ParsedLog<'a>) -> Vec<PlayerStats<'a>> {
+    let mut stats: Vec<PlayerStats<'a>> = log
+        .players
+        .iter()
+        .map(|p| PlayerStats {
+            name: p.name.clone(),
+            champions: p.champions.clone(),
+            messages: MessageCounts::default(),
+            first_bloods: 0,
+            shutdowns_dealt: 0,
+            shutdowns_received: 0,
+            bounty_gold: 0,
+            pings: PingCounts::default(),
+            purchases: 0,
+            targets_made: 0,
+            targets_received: 0,
+            first_seen: None,
+            last_seen: None,
+        })
+        .collect();
+    let index: HashMap<&str, usize> = log
+        .players
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.name.as_ref(), i))
+        .collect();
+    let player = |name: Option<&str>| name.and_then(|n| index.get(n).copied());
+
+    for m in &log.messages {
+        let Some(i) = player(Some(m.player.as_ref())) else {
+            continue;
+        };
+        let counts = &mut stats[i].messages;
+        match m.channel {
+            ChatChannel::All => counts.all += 1,
+            ChatChannel::Team => counts.team += 1,
+            ChatChannel::Party => counts.party += 1,
+            ChatChannel::Player => counts.player += 1,
+        }
+        stats[i].seen(m.time);
+    }
+
+    for k in &log.kills {
+        if let Some(i) = player(k.killer.as_deref()) {
+            let killer = &mut stats[i];
+            killer.first_bloods += u32::from(k.is_first_blood);
+            killer.shutdowns_dealt += u32::from(k.is_shutdown);
+            killer.bounty_gold += k.bounty.unwrap_or_default();
+            killer.seen(k.time);
+        }
+        if let Some(i) = player(k.victim.as_deref()) {
+            stats[i].shutdowns_received += u32::from(k.is_shutdown);
+            stats[i].seen(k.time);
+        }
+    }
+
+    for e in &log.events {
+        if let Some(i) = player(e.event.actor().map(|(name, _)| name)) {
+            let actor = &mut stats[i];
+            match &e.event {
+                LogEvent::Ping { kind, .. } => {
+                    let pings = &mut actor.pings;
+                    match kind {
+                        PingKind::OnTheWay => pings.on_the_way += 1,
+                        PingKind::Missing => pings.missing += 1,
+                        PingKind::Retreating => pings.retreating += 1,
+                        PingKind::InDanger => pings.in_danger += 1,
+                        PingKind::NeedsVision => pings.needs_vision += 1,
+                    }
+                }
+                LogEvent::Purchase { .. } => actor.purchases += 1,
+                LogEvent::TargetPlayer { .. } | LogEvent::TargetObjective { .. } => {
+                    actor.targets_made += 1;
+                }
+                _ => {}
+            }
+            actor.seen(e.time);
+        }
+        if let Some(i) = player(e.event.subject().map(|(name, _)| name)) {
+            if let LogEvent::TargetPlayer { .. } = e.event {
+                stats[i].targets_received += 1;
+            }
+            stats[i].seen(e.time);
+        }
+    }
+
+    for u in &log.unclassified {
+        if let Some(i) = player(u.player.as_deref()) {
+            stats[i].seen(u.time);
+        }
+    }
+
+    stats
+}
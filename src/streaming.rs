@@ -0,0 +1,392 @@
+use std::borrow::Cow;
+use std::io::{self, BufRead};
+
+use pest::iterators::Pair;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    build_record, is_recognized, parse_line, register_players, rejected_line, ChatMessage,
+    Diagnostic, GameTime, KillEvent, Locale, ObjectiveEvent, PlayerSummary, Players, RecordKind,
+    RiotId, Rule, SourceSpan, SystemLine, UnclassifiedLine,
+};
+
+/// An owned record produced by [`LogParser`] for one input line. Serializes
+/// as `{"kind": "kill", "record": {...}}`; the record is nested because some
+/// events have a `kind` field of their own.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "kind", content = "record", rename_all = "snake_case")]
+pub enum LogRecord<'a> {
+    Message(ChatMessage<'a>),
+    Kill(KillEvent<'a>),
+    Event(ObjectiveEvent<'a>),
+    System(SystemLine<'a>),
+    Unclassified(UnclassifiedLine<'a>),
+}
+
+impl LogRecord<'_> {
+    pub fn kind(&self) -> RecordKind {
+        match self {
+            LogRecord::Message(_) => RecordKind::Message,
+            LogRecord::Kill(_) => RecordKind::Kill,
+            LogRecord::Event(_) => RecordKind::Event,
+            LogRecord::System(_) => RecordKind::System,
+            LogRecord::Unclassified(_) => RecordKind::Unclassified,
+        }
+    }
+
+    /// Game time of the record; system lines have none.
+    pub fn time(&self) -> Option<GameTime> {
+        match self {
+            LogRecord::Message(m) => Some(m.time),
+            LogRecord::Kill(k) => Some(k.time),
+            LogRecord::Event(e) => Some(e.time),
+            LogRecord::System(_) => None,
+            LogRecord::Unclassified(u) => Some(u.time),
+        }
+    }
+
+    pub fn into_owned(self) -> LogRecord<'static> {
+        match self {
+            LogRecord::Message(m) => LogRecord::Message(m.into_owned()),
+            LogRecord::Kill(k) => LogRecord::Kill(k.into_owned()),
+            LogRecord::Event(e) => LogRecord::Event(e.into_owned()),
+            LogRecord::System(s) => LogRecord::System(s.into_owned()),
+            LogRecord::Unclassified(u) => LogRecord::Unclassified(u.into_owned()),
+        }
+    }
+}
+
+/// Everything [`LogParser::feed_line`] learned from one non-blank line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedLine<'a> {
+    pub source: SourceSpan,
+    /// Lines that were not fully recognized are kept as system or
+    /// unclassified records; `diagnostics` then says why.
+    pub record: LogRecord<'a>,
+    pub diagnostics: Vec<Diagnostic<'a>>,
+}
+
+impl ParsedLine<'_> {
+    pub fn into_owned(self) -> ParsedLine<'static> {
+        ParsedLine {
+            source: self.source,
+            record: self.record.into_owned(),
+            diagnostics: self
+                .diagnostics
+                .into_iter()
+                .map(Diagnostic::into_owned)
+                .collect(),
+        }
+    }
+}
+
+/// Incremental parser: feed it lines as they arrive and get one typed
+/// record per line back, without keeping the log in memory. Only the
+/// player registry grows, with the number of distinct players.
+///
+/// [`feed_line`](LogParser::feed_line) borrows record text from lines that
+/// live as long as the parser, e.g. slices of a log read into memory;
+/// [`feed_line_owned`](LogParser::feed_line_owned) copies it instead.
+///
+/// ```
+/// use lol_chat_parser::{LogParser, LogRecord};
+///
+/// let mut parser = LogParser::new();
+/// let parsed = parser.feed_line("00:42 uskin432 (Warwick) is on the way\n").unwrap();
+/// assert!(matches!(parsed.record, LogRecord::Event(_)));
+/// assert_eq!(parser.players()[0].name, "uskin432");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LogParser<'a> {
+    /// `None` until a localized line is seen, see [`LogParser::new`].
+    locale: Option<Locale>,
+    players: Players<'a>,
+    line: usize,
+    offset: usize,
+    /// Lines fed without a terminator are counted as ending in `\r\n`.
+    crlf: bool,
+}
+
+impl<'a> LogParser<'a> {
+    /// A parser that detects the client language on the fly: the first line
+    /// only a built-in [`Locale`] can read fixes the language for the rest of
+    /// the stream.
+    ///
+    /// Logs that mix languages are not supported: once the language is
+    /// fixed, lines in another one stay unclassified, and rendering such a
+    /// log keeps them as written, so a second parse may pick a different
+    /// language.
+    pub fn new() -> Self {
+        LogParser::default()
+    }
+
+    pub fn with_locale(locale: Locale) -> Self {
+        LogParser {
+            locale: Some(locale),
+            ..LogParser::default()
+        }
+    }
+
+    /// Counts lines fed without a terminator as ending in `\r\n` rather
+    /// than `\n`, so that the spans of a CRLF log split with [`str::lines`]
+    /// match those of [`parse_log`](crate::parse_log).
+    pub fn with_crlf(mut self) -> Self {
+        self.crlf = true;
+        self
+    }
+
+    /// Client language in use, if known yet.
+    pub fn locale(&self) -> Option<&Locale> {
+        self.locale.as_ref()
+    }
+
+    /// Parses the next line of the log. `line` may keep its terminator;
+    /// without one, a `\n` is assumed when counting byte offsets, or a
+    /// `\r\n` after [`with_crlf`](LogParser::with_crlf).
+    /// Blank lines return `None` but still advance the line number.
+    pub fn feed_line(&mut self, line: &'a str) -> Option<ParsedLine<'a>> {
+        let mut diagnostics = Vec::new();
+        let (source, record) = self.feed(line, &mut diagnostics)?;
+
+        Some(ParsedLine {
+            source,
+            record,
+            diagnostics,
+        })
+    }
+
+    /// Like [`feed_line`](LogParser::feed_line) for a line that does not
+    /// outlive the call, e.g. a reused read buffer.
+    pub fn feed_line_owned(&mut self, line: &str) -> Option<ParsedLine<'static>> {
+        let (source, trimmed) = self.next_line(line)?;
+        let mut diagnostics = Vec::new();
+        let record = self.parse(trimmed, source, &mut diagnostics).into_owned();
+        self.register(&record, source, trimmed, &mut diagnostics);
+
+        Some(ParsedLine {
+            source,
+            record,
+            diagnostics: diagnostics
+                .into_iter()
+                .map(Diagnostic::into_owned)
+                .collect(),
+        })
+    }
+
+    /// Like [`feed_line`](LogParser::feed_line), adding the diagnostics of the
+    /// line to `diagnostics` instead of returning them.
+    pub(crate) fn feed(
+        &mut self,
+        line: &'a str,
+        diagnostics: &mut Vec<Diagnostic<'a>>,
+    ) -> Option<(SourceSpan, LogRecord<'a>)> {
+        let (source, trimmed) = self.next_line(line)?;
+        let record = self.parse(trimmed, source, diagnostics);
+        self.register(&record, source, trimmed, diagnostics);
+        Some((source, record))
+    }
+
+    /// Streams records from `reader` line by line.
+    pub fn records<R: BufRead>(self, reader: R) -> Records<'a, R> {
+        Records {
+            parser: self,
+            reader,
+            buf: String::new(),
+        }
+    }
+
+    /// Players seen so far, sorted by name, with their champions.
+    pub fn players(&self) -> Vec<PlayerSummary<'a>> {
+        summaries(
+            self.players
+                .iter()
+                .map(|(name, champs)| (name.clone(), champs.iter().cloned().collect())),
+        )
+    }
+
+    /// Like [`players`](LogParser::players), moving the names out of the
+    /// registry instead of copying them.
+    pub(crate) fn into_players(self) -> Vec<PlayerSummary<'a>> {
+        summaries(
+            self.players
+                .into_iter()
+                .map(|(name, champs)| (name, champs.into_iter().collect())),
+        )
+    }
+
+    /// Advances the line number and byte offset past `line`, returning its
+    /// span and trimmed text unless it is blank.
+    fn next_line<'l>(&mut self, line: &'l str) -> Option<(SourceSpan, &'l str)> {
+        let start = self.offset;
+        self.line += 1;
+        self.offset += line.len();
+        if !line.ends_with('\n') {
+            self.offset += if self.crlf { 2 } else { 1 };
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let start = start + (line.len() - line.trim_start().len());
+        let source = SourceSpan {
+            line: self.line,
+            start,
+            end: start + trimmed.len(),
+        };
+        Some((source, trimmed))
+    }
+
+    /// Builds the record for one trimmed line; rewritten localized lines are
+    /// owned, everything else borrows from `trimmed`.
+    fn parse<'l>(
+        &mut self,
+        trimmed: &'l str,
+        source: SourceSpan,
+        diagnostics: &mut Vec<Diagnostic<'l>>,
+    ) -> LogRecord<'l> {
+        let english = parse_line(trimmed);
+        if english.as_ref().is_ok_and(is_recognized) {
+            return line_record(english, trimmed, source, diagnostics);
+        }
+
+        match self.localize(trimmed, source, diagnostics) {
+            Some(record) => record,
+            None => line_record(english, trimmed, source, diagnostics),
+        }
+    }
+
+    fn register<'t>(
+        &mut self,
+        record: &LogRecord<'a>,
+        source: SourceSpan,
+        text: &'t str,
+        diagnostics: &mut Vec<Diagnostic<'t>>,
+    ) {
+        register_players(&mut self.players, record, |severity, rule, message| {
+            diagnostics.push(Diagnostic {
+                severity,
+                line: source.line,
+                rule,
+                message,
+                text: Cow::Borrowed(text),
+            });
+        });
+    }
+
+    /// Builds the record from the first English rendering of a localized
+    /// line that the grammar recognizes, using the known locale or, while it
+    /// is still unknown, the first built-in locale that can read the line.
+    /// Each rendering is parsed once.
+    fn localize<'l>(
+        &mut self,
+        trimmed: &'l str,
+        source: SourceSpan,
+        diagnostics: &mut Vec<Diagnostic<'l>>,
+    ) -> Option<LogRecord<'l>> {
+        let (time, body) = trimmed.split_once(' ')?;
+        let current = self.locale;
+        let known;
+        let locales: &[&Locale] = match &current {
+            Some(locale) => {
+                known = [locale];
+                &known
+            }
+            None => &Locale::ALL[1..],
+        };
+
+        for locale in locales {
+            for english in locale.translate(body) {
+                let candidate = format!("{time} {english}");
+                let Ok(line_pair) = parse_line(&candidate) else {
+                    continue;
+                };
+                if is_recognized(&line_pair) {
+                    let record = build_record(line_pair, trimmed, source, diagnostics);
+                    self.locale = Some(**locale);
+                    return Some(record.into_owned());
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Player summaries sorted by name, each with its champions sorted.
+fn summaries<'a>(
+    players: impl Iterator<Item = (Cow<'a, str>, Vec<Cow<'a, str>>)>,
+) -> Vec<PlayerSummary<'a>> {
+    let mut summaries: Vec<PlayerSummary<'a>> = players
+        .map(|(name, mut champions)| {
+            champions.sort();
+            PlayerSummary {
+                riot_id: RiotId::from_name(&name),
+                name,
+                champions,
+            }
+        })
+        .collect();
+
+    summaries.sort_by(|a, b| a.name.cmp(&b.name));
+    summaries
+}
+
+/// Record for a parsed (or rejected) line; `text` is the original line.
+fn line_record<'p>(
+    parsed: Result<Pair<'p, Rule>, Box<pest::error::Error<Rule>>>,
+    text: &'p str,
+    source: SourceSpan,
+    diagnostics: &mut Vec<Diagnostic<'p>>,
+) -> LogRecord<'p> {
+    match parsed {
+        Ok(line_pair) => build_record(line_pair, text, source, diagnostics),
+        Err(err) => {
+            diagnostics.push(rejected_line(source, text, &err));
+            LogRecord::System(SystemLine {
+                text: Cow::Borrowed(text),
+                source,
+            })
+        }
+    }
+}
+
+/// Iterator over the records of a [`BufRead`], see [`LogParser::records`].
+#[derive(Debug)]
+pub struct Records<'a, R> {
+    parser: LogParser<'a>,
+    reader: R,
+    buf: String,
+}
+
+impl<'a, R> Records<'a, R> {
+    /// The underlying parser, e.g. to read the player registry mid-stream.
+    pub fn parser(&self) -> &LogParser<'a> {
+        &self.parser
+    }
+
+    pub fn into_parser(self) -> LogParser<'a> {
+        self.parser
+    }
+}
+
+/// Yields owned lines, since the read buffer is reused.
+impl<R: BufRead> Iterator for Records<'_, R> {
+    type Item = io::Result<ParsedLine<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buf.clear();
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    if let Some(parsed) = self.parser.feed_line_owned(&self.buf) {
+                        return Some(Ok(parsed));
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
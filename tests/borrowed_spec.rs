@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+use lol_chat_parser::{parse_log, parse_log_with_locale, Locale, ParsedLog};
+
+static LOG: &str = "\
+00:42 uskin432 (Warwick) is on the way
+00:52 kozakSyla (Lux) has drawn first blood!
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+";
+
+/// Whether `text` is a slice of `input` rather than a copy.
+fn borrows_from(text: &str, input: &str) -> bool {
+    input.as_bytes().as_ptr_range().contains(&text.as_ptr())
+}
+
+#[test]
+fn record_text_is_sliced_from_the_input() {
+    let parsed = parse_log(LOG);
+
+    let message = &parsed.messages[0];
+    assert!(borrows_from(&message.player, LOG));
+    assert!(borrows_from(&message.champion, LOG));
+    assert!(borrows_from(&message.text, LOG));
+
+    let kill = &parsed.kills[1];
+    assert!(borrows_from(kill.killer.as_deref().unwrap(), LOG));
+    assert!(borrows_from(kill.victim.as_deref().unwrap(), LOG));
+    assert!(borrows_from(kill.bounty_text.as_deref().unwrap(), LOG));
+
+    let (player, champion) = parsed.events[0].event.actor().unwrap();
+    assert!(borrows_from(player, LOG));
+    assert_eq!((player, champion), ("uskin432", "Warwick"));
+
+    assert!(parsed.players.iter().all(|p| borrows_from(&p.name, LOG)));
+}
+
+#[test]
+fn localized_lines_are_owned() {
+    let log = "00:42 uskin432 (Warwick) вже в дорозі\n";
+    let parsed = parse_log_with_locale(log, &Locale::UKRAINIAN);
+
+    let (player, _) = parsed.events[0].event.actor().unwrap();
+    assert_eq!(player, "uskin432");
+    assert!(!borrows_from(player, log));
+}
+
+#[test]
+fn into_owned_outlives_the_input() {
+    let owned: ParsedLog<'static> = {
+        let input = LOG.to_string();
+        parse_log(&input).into_owned()
+    };
+
+    assert_eq!(owned, parse_log(LOG));
+    assert!(matches!(owned.messages[0].text, Cow::Owned(_)));
+}
@@ -0,0 +1,89 @@
+use lol_chat_parser::{canonical_champion, parse_log, Rule, Severity, CHAMPIONS};
+
+#[test]
+fn canonical_champion_ignores_case_spacing_and_punctuation() {
+    assert_eq!(canonical_champion("Kaisa"), Some("Kai'Sa"));
+    assert_eq!(canonical_champion("KAI'SA"), Some("Kai'Sa"));
+    assert_eq!(canonical_champion("Dr Mundo"), Some("Dr. Mundo"));
+    assert_eq!(canonical_champion("nunu & willump"), Some("Nunu & Willump"));
+    assert_eq!(
+        canonical_champion("Nunu and Willump"),
+        Some("Nunu & Willump")
+    );
+    assert_eq!(canonical_champion("MonkeyKing"), Some("Wukong"));
+    assert_eq!(canonical_champion("Renata"), Some("Renata Glasc"));
+    assert_eq!(canonical_champion("Teemo Prime"), None);
+    assert_eq!(canonical_champion("&"), None);
+}
+
+#[test]
+fn every_table_entry_is_its_own_canonical_name() {
+    for champion in CHAMPIONS {
+        assert_eq!(canonical_champion(champion), Some(*champion));
+    }
+}
+
+#[test]
+fn champion_table_is_sorted_for_exact_lookups() {
+    assert!(CHAMPIONS.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn player_summary_holds_normalized_champion_names() {
+    let log = "\
+01:00 [All] Pumpkin (Nunu & Willump): hi
+01:05 Pumpkin (Nunu and Willump) is on the way
+01:10 Surgeon (Dr. Mundo) has slain Pumpkin (Nunu & Willump)!
+01:15 [Team] Archer (Kaisa): gank bot
+";
+
+    let parsed = parse_log(log);
+
+    let players: Vec<_> = parsed
+        .players
+        .iter()
+        .map(|p| (p.name.as_ref(), p.champions.clone()))
+        .collect();
+    assert_eq!(
+        players,
+        vec![
+            ("Archer", vec!["Kai'Sa".into()]),
+            ("Pumpkin", vec!["Nunu & Willump".into()]),
+            ("Surgeon", vec!["Dr. Mundo".into()]),
+        ]
+    );
+    assert_eq!(parsed.messages[1].champion, "Kaisa");
+    assert!(parsed.diagnostics.is_empty());
+}
+
+#[test]
+fn unknown_champions_are_kept_and_flagged() {
+    let parsed = parse_log("01:00 [All] Tester (Teemo Prime): hi");
+
+    assert_eq!(parsed.players[0].champions, vec!["Teemo Prime".to_string()]);
+    assert_eq!(parsed.diagnostics.len(), 1);
+    let diagnostic = &parsed.diagnostics[0];
+    assert_eq!(diagnostic.severity, Severity::Info);
+    assert_eq!(diagnostic.line, 1);
+    assert_eq!(diagnostic.rule, Rule::champion_name);
+    assert!(diagnostic.message.contains("Teemo Prime"));
+}
+
+#[test]
+fn unknown_champions_are_flagged_once_per_player() {
+    let parsed = parse_log(
+        "\
+01:00 [All] Tester (Teemo Prime): hi
+01:05 [All] Tester (Teemo Prime): hi again
+01:10 Tester (Teemo Prime) has slain Other (Teemo Prime)!
+",
+    );
+
+    let flagged: Vec<_> = parsed
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == Rule::champion_name)
+        .map(|d| d.line)
+        .collect();
+    assert_eq!(flagged, [1, 3]);
+}
@@ -0,0 +1,37 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn temp_log(name: &str) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("lol_chat_parser_{}_{name}.txt", std::process::id()));
+    fs::write(&path, "00:42 uskin432 (Warwick) is on the way\n").unwrap();
+    path
+}
+
+fn run(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_lol_chat_parser"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn html_refuses_to_overwrite_the_page_next_to_the_log() {
+    let log = temp_log("html_overwrite");
+    let html = log.with_extension("html");
+    fs::write(&html, "keep me").unwrap();
+
+    let output = run(&["html", log.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("refusing to overwrite"));
+    assert_eq!(fs::read_to_string(&html).unwrap(), "keep me");
+
+    fs::remove_file(&html).unwrap();
+    let output = run(&["html", log.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert!(fs::read_to_string(&html).unwrap().contains("uskin432"));
+
+    fs::remove_file(&html).unwrap();
+    fs::remove_file(&log).unwrap();
+}
@@ -0,0 +1,118 @@
+use lol_chat_parser::{parse_log, to_csv_string, CsvTable, OutputOptions, TimeFormat};
+
+const LOG: &str = "\
+00:42 uskin432 (Warwick) is on the way
+05:00 Golf4f (Mel) has slain BorysBulba (Tahm Kench)!
+08:00 Enemy team has slain the Infernal Drake!
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bounty: 1,050G)
+16:53 [All] piwkobb (Yone): gg, \"wp\" all
+18:32 kozakSyla (Lux) has targeted the Power Flower (33%)
+";
+
+fn table(table: CsvTable, options: OutputOptions) -> Vec<String> {
+    to_csv_string(&parse_log(LOG), table, options)
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn chat_text_with_commas_and_quotes_is_quoted() {
+    let rows = table(CsvTable::Messages, OutputOptions::default());
+    assert_eq!(rows[0], "time,channel,player,champion,text,line");
+    assert_eq!(rows[1], "16:53,all,piwkobb,Yone,\"gg, \"\"wp\"\" all\",5");
+}
+
+#[test]
+fn every_table_has_a_header_and_one_row_per_record() {
+    let kills = table(CsvTable::Kills, OutputOptions::default());
+    assert_eq!(kills.len(), 3);
+    assert_eq!(
+        kills[2],
+        "13:05,Golf4f,Mel,champion,BorysBulba,Tahm Kench,1050,\"Bounty: 1,050G\",parsed,true,false,false,4"
+    );
+
+    let events = table(CsvTable::Events, OutputOptions::default());
+    assert_eq!(
+        events[0],
+        "time,type,player,champion,target,target_champion,team,objective,element,item,ping,level,percent,feat,line"
+    );
+    assert_eq!(
+        events[1],
+        "00:42,ping,uskin432,Warwick,,,,,,,on_the_way,,,,1"
+    );
+    assert_eq!(
+        events[2],
+        "08:00,objective,,,,,enemy,dragon,infernal,,,,,,3"
+    );
+    assert_eq!(
+        events[3],
+        "18:32,target_objective,kozakSyla,Lux,,,,Power Flower,,,,,33,,6"
+    );
+
+    let players = table(CsvTable::Players, OutputOptions::default());
+    assert_eq!(players[0], "name,game_name,tag_line,champions");
+    assert!(players.contains(&"Golf4f,Golf4f,,Mel".to_string()));
+}
+
+#[test]
+fn output_options_apply_to_csv() {
+    let options = OutputOptions {
+        time_format: TimeFormat::Seconds,
+        include_source: false,
+    };
+    let messages = table(CsvTable::Messages, options);
+    assert_eq!(messages[0], "time,channel,player,champion,text");
+    assert!(messages[1].starts_with("1013,all,"));
+}
+
+#[test]
+fn tables_are_named_on_the_command_line() {
+    for &t in CsvTable::ALL {
+        assert_eq!(CsvTable::from_name(t.name()), Some(t));
+    }
+    assert_eq!(CsvTable::from_name("Kills"), Some(CsvTable::Kills));
+    assert_eq!(CsvTable::from_name("diagnostics"), None);
+}
+
+#[test]
+fn event_columns_match_the_json_names() {
+    let log = parse_log(
+        "\
+01:00 uskin432 (Warwick) needs vision
+02:00 Golf4f (Mel) got a penta kill!
+03:00 Golf4f (Mel) has ended BorysBulba (Tahm Kench)'s killing spree!
+04:00 Enemy team has slain the Cloud Drake!
+05:00 Ally team has slain the Baron Nashor!
+06:00 BorysBulba (Tahm Kench) purchased Control Ward
+",
+    );
+    let options = OutputOptions {
+        include_source: false,
+        ..OutputOptions::default()
+    };
+    let csv = to_csv_string(&log, CsvTable::Events, options);
+    let rows: Vec<Vec<&str>> = csv
+        .lines()
+        .skip(1)
+        .map(|r| r.split(',').collect())
+        .collect();
+    assert_eq!(rows.len(), log.events.len());
+
+    for (row, event) in rows.iter().zip(&log.events) {
+        let json = serde_json::to_value(&event.event).unwrap();
+        let text = |value: &serde_json::Value| value.as_str().unwrap_or_default().to_string();
+        assert_eq!(row[1], text(&json["type"]));
+        assert_eq!(row[6], text(&json["team"]));
+        let objective = &json["objective"];
+        if objective.is_object() {
+            assert_eq!(row[7], text(&objective["kind"]));
+            assert_eq!(row[8], text(&objective["element"]));
+        }
+        assert_eq!(row[9], text(&json["item"]));
+        if json["type"] == "ping" {
+            assert_eq!(row[10], text(&json["kind"]));
+        }
+        assert_eq!(row[11], text(&json["level"]));
+    }
+}
@@ -0,0 +1,145 @@
+use lol_chat_parser::{RecordKind, Rule, Severity, parse_log, parse_log_strict};
+
+#[test]
+fn sample_log_only_reports_the_help_banner() {
+    let log = "\
+Type /help for a list of commands
+00:42 uskin432 (Warwick) is on the way
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+17:34 [Team] kozakSyla (Lux): hi in team chat
+";
+
+    let parsed = parse_log(log);
+
+    assert_eq!(parsed.diagnostics.len(), 1);
+    let banner = &parsed.diagnostics[0];
+    assert_eq!(banner.severity, Severity::Info);
+    assert_eq!(banner.line, 1);
+    assert_eq!(banner.rule, Rule::line);
+    assert_eq!(banner.text, "Type /help for a list of commands");
+    assert!(!banner.message.is_empty());
+
+    assert!(parse_log_strict(log).is_ok());
+}
+
+#[test]
+fn unrecognized_and_partial_lines_are_reported() {
+    let log = "\
+13:75 kozakSyla (Lux) is on the way
+14:00 piwkobb (Yone) has stolen the Baron
+14:01 Something brand new happened
+14:02 BorysBulba (Tahm Kench) is on rampage! again
+";
+
+    let parsed = parse_log(log);
+    let seen: Vec<_> = parsed
+        .diagnostics
+        .iter()
+        .map(|d| (d.line, d.severity, d.rule))
+        .collect();
+
+    assert_eq!(
+        seen,
+        vec![
+            (1, Severity::Error, Rule::line),
+            (2, Severity::Warning, Rule::generic_player_event),
+            (3, Severity::Warning, Rule::generic_text),
+            (4, Severity::Warning, Rule::generic_player_event),
+        ]
+    );
+    // A rule matching only a prefix does not win: the whole tail is kept.
+    assert!(parsed.events.is_empty());
+    assert_eq!(parsed.unclassified[2].text, "is on rampage! again");
+
+    let errors = parse_log_strict(log).unwrap_err();
+    assert_eq!(errors.diagnostics.len(), 4);
+    assert!(
+        errors
+            .to_string()
+            .starts_with("4 line(s) were not fully parsed")
+    );
+}
+
+#[test]
+fn fallback_lines_are_kept_as_unclassified() {
+    let log = "\
+14:00 piwkobb (Yone) has stolen the Baron
+14:01 Something brand new happened
+";
+
+    let parsed = parse_log(log);
+
+    assert_eq!(parsed.unclassified.len(), 2);
+
+    let stolen = &parsed.unclassified[0];
+    assert_eq!(stolen.time.to_string(), "14:00");
+    assert_eq!(stolen.player.as_deref(), Some("piwkobb"));
+    assert_eq!(stolen.champion.as_deref(), Some("Yone"));
+    assert_eq!(stolen.text, "has stolen the Baron");
+
+    let anonymous = &parsed.unclassified[1];
+    assert_eq!(anonymous.player, None);
+    assert_eq!(anonymous.champion, None);
+    assert_eq!(anonymous.text, "Something brand new happened");
+
+    let kinds: Vec<_> = parsed.timeline().map(|e| e.record.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![RecordKind::Unclassified, RecordKind::Unclassified]
+    );
+    assert!(parsed.players.iter().any(|p| p.name == "piwkobb"));
+}
+
+#[test]
+fn partial_matches_fall_back_to_unclassified() {
+    let log = "\
+00:42 X (Y) is on the way and back
+00:43 [All] X (Y) is on the way
+";
+
+    let parsed = parse_log(log);
+
+    assert!(parsed.events.is_empty());
+    let texts: Vec<_> = parsed
+        .unclassified
+        .iter()
+        .map(|u| u.text.as_ref())
+        .collect();
+    assert_eq!(
+        texts,
+        vec!["is on the way and back", "[All] X (Y) is on the way"]
+    );
+    assert_eq!(parsed.unclassified[0].player.as_deref(), Some("X"));
+}
+
+#[test]
+fn lines_with_rejected_values_stay_in_the_timeline() {
+    let log = "\
+4294967295:00 Foo (Lux): hi
+18:32 piwkobb (Yone) has targeted the Power Flower (99999999999%)
+";
+
+    let parsed = parse_log(log);
+
+    let seen: Vec<_> = parsed
+        .diagnostics
+        .iter()
+        .map(|d| (d.line, d.severity, d.rule))
+        .collect();
+    assert_eq!(
+        seen,
+        vec![
+            (1, Severity::Error, Rule::time),
+            (2, Severity::Error, Rule::target_objective_event),
+        ]
+    );
+
+    let kinds: Vec<_> = parsed.timeline().map(|e| e.record.kind()).collect();
+    assert_eq!(kinds, vec![RecordKind::System, RecordKind::Unclassified]);
+    assert_eq!(parsed.system[0].text, "4294967295:00 Foo (Lux): hi");
+    assert_eq!(parsed.unclassified[0].time.to_string(), "18:32");
+    assert_eq!(
+        parsed.unclassified[0].text,
+        "piwkobb (Yone) has targeted the Power Flower (99999999999%)"
+    );
+}
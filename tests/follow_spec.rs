@@ -0,0 +1,97 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use lol_chat_parser::{LogFollower, LogParser, LogRecord, ParsedLine};
+
+fn temp_log(name: &str) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("lol_chat_parser_{}_{name}.txt", std::process::id()));
+    fs::write(&path, "").unwrap();
+    path
+}
+
+fn append(path: &PathBuf, text: &str) {
+    let mut file = OpenOptions::new().append(true).open(path).unwrap();
+    file.write_all(text.as_bytes()).unwrap();
+}
+
+fn lines(parsed: &[ParsedLine]) -> Vec<usize> {
+    parsed.iter().map(|p| p.source.line).collect()
+}
+
+#[test]
+fn poll_returns_only_new_complete_lines() -> anyhow::Result<()> {
+    let path = temp_log("append");
+    append(&path, "00:42 uskin432 (Warwick) is on the way\n");
+    let mut follower = LogFollower::open(&path, LogParser::new())?;
+
+    assert_eq!(lines(&follower.poll()?), [1]);
+    assert!(follower.poll()?.is_empty());
+
+    append(&path, "00:52 kozakSyla (Lux) has drawn");
+    assert!(follower.poll()?.is_empty());
+
+    append(
+        &path,
+        " first blood!\n13:10 BorysBulba (Tahm Kench) purchased Control Ward\n",
+    );
+    let parsed = follower.poll()?;
+    assert_eq!(lines(&parsed), [2, 3]);
+    assert!(matches!(
+        parsed[0].record,
+        LogRecord::Kill(ref kill) if kill.is_first_blood
+    ));
+    assert_eq!(follower.parser().players().len(), 3);
+
+    fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn truncated_file_is_read_again_from_the_start() -> anyhow::Result<()> {
+    let path = temp_log("truncate");
+    append(
+        &path,
+        "00:42 uskin432 (Warwick) is on the way\n00:43 uskin432 (Warwick) is missing\n",
+    );
+    let mut follower = LogFollower::open(&path, LogParser::new())?;
+    assert_eq!(lines(&follower.poll()?), [1, 2]);
+
+    fs::write(&path, "00:05 piwkobb (Yone) is on the way\n")?;
+    let parsed = follower.poll()?;
+    assert_eq!(lines(&parsed), [1]);
+    let names: Vec<_> = follower
+        .parser()
+        .players()
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    assert_eq!(names, ["piwkobb"]);
+
+    fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn rotated_file_is_followed_under_the_same_path() -> anyhow::Result<()> {
+    let path = temp_log("rotate");
+    let rotated = path.with_extension("old");
+    append(&path, "00:42 uskin432 (Warwick) is on the way\n");
+    let mut follower = LogFollower::open(&path, LogParser::new())?;
+    assert_eq!(lines(&follower.poll()?), [1]);
+
+    fs::rename(&path, &rotated)?;
+    assert!(follower.poll()?.is_empty());
+
+    fs::write(
+        &path,
+        "00:01 piwkobb (Yone) is on the way\n00:02 piwkobb (Yone) is missing\n",
+    )?;
+    assert_eq!(lines(&follower.poll()?), [1, 2]);
+
+    fs::remove_file(&path)?;
+    fs::remove_file(&rotated)?;
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use lol_chat_parser::{GameTime, OutputOptions, TimeFormat, parse_log, to_json_string};
+use serde_json::{Value, to_value};
+
+#[test]
+fn game_time_parses_displays_and_orders() -> Result<()> {
+    let early: GameTime = "00:52".parse()?;
+    let late: GameTime = "13:05".parse()?;
+
+    assert_eq!(early.as_seconds(), 52);
+    assert_eq!(late.as_seconds(), 13 * 60 + 5);
+    assert_eq!(late.to_string(), "13:05");
+    assert!(early < late);
+
+    assert_eq!((late - early).to_string(), "12:13");
+    assert_eq!(early - late, GameTime::ZERO);
+    assert_eq!(early.checked_sub(late), None);
+    assert_eq!(
+        early + GameTime::from_seconds(8),
+        GameTime::from_minutes_seconds(1, 0)
+    );
+    assert_eq!(
+        late.bucket(GameTime::from_minutes_seconds(5, 0))
+            .to_string(),
+        "10:00"
+    );
+
+    assert!("1305".parse::<GameTime>().is_err());
+    Ok(())
+}
+
+#[test]
+fn game_time_accepts_long_games_and_hours() -> Result<()> {
+    assert_eq!("100:12".parse::<GameTime>()?.as_seconds(), 100 * 60 + 12);
+    assert_eq!("1:40:12".parse::<GameTime>()?.as_seconds(), 100 * 60 + 12);
+    assert_eq!("1:40:12".parse::<GameTime>()?.to_string(), "100:12");
+
+    assert!("13:60".parse::<GameTime>().is_err());
+    assert!("1:60:00".parse::<GameTime>().is_err());
+    assert!("13:5".parse::<GameTime>().is_err());
+    assert!("1:2:3:4".parse::<GameTime>().is_err());
+    Ok(())
+}
+
+#[test]
+fn parse_log_keeps_long_game_lines_timed() {
+    let log = "\
+100:12 kozakSyla (Lux): still going
+1:40:30 [All] piwkobb (Yone): gg
+13:75 not a valid clock
+";
+
+    let parsed = parse_log(log);
+
+    let times: Vec<_> = parsed.messages.iter().map(|m| m.time.to_string()).collect();
+    assert_eq!(times, vec!["100:12", "100:30"]);
+    assert_eq!(parsed.system.len(), 1);
+    assert_eq!(parsed.system[0].text, "13:75 not a valid clock");
+}
+
+#[test]
+fn game_time_serializes_as_clock_by_default_and_seconds_on_request() -> Result<()> {
+    let parsed = parse_log("13:10 BorysBulba (Tahm Kench) purchased Control Ward");
+
+    let json: Value = to_value(&parsed.events[0])?;
+    assert_eq!(json["time"], "13:10");
+
+    let options = OutputOptions {
+        time_format: TimeFormat::Seconds,
+        ..OutputOptions::default()
+    };
+    let json: Value = serde_json::from_str(&to_json_string(&parsed.events[0], options)?)?;
+    assert_eq!(json["time"], 790);
+
+    // Serializing without the options keeps the default.
+    let json: Value = to_value(&parsed.events[0])?;
+    assert_eq!(json["time"], "13:10");
+    Ok(())
+}
@@ -2,6 +2,16 @@ use anyhow::Result;
 use pest::Parser;
 use lol_chat_parser::{LolChatParser, Rule};
 
+/// Parses a whole line body and returns the event rule that matched after
+/// the actor, if any.
+fn event_rule(input: &str) -> Result<Rule> {
+    let body = LolChatParser::parse(Rule::line_body, input)?
+        .next()
+        .unwrap();
+    assert_eq!(body.as_str(), input);
+    Ok(body.into_inner().last().unwrap().as_rule())
+}
+
 #[test]
 fn rule_time_parses_timestamp() -> Result<()> {
     let mut pairs = LolChatParser::parse(Rule::time, "17:34")?;
@@ -11,6 +21,23 @@ fn rule_time_parses_timestamp() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rule_time_parses_long_games_and_hours() -> Result<()> {
+    for input in ["100:12", "1:40:12", "7:05"] {
+        let mut pairs = LolChatParser::parse(Rule::time, input)?;
+        let p = pairs.next().unwrap();
+        assert_eq!(p.as_rule(), Rule::time);
+        assert_eq!(p.as_str(), input);
+    }
+    Ok(())
+}
+
+#[test]
+fn rule_time_rejects_seconds_above_59() {
+    assert!(LolChatParser::parse(Rule::time, "13:75").is_err());
+    assert!(LolChatParser::parse(Rule::time, "13:5").is_err());
+}
+
 #[test]
 fn rule_line_parses_full_line() -> Result<()> {
     let input = "17:34 [Team] kozakSyla (Lux): hi in team chat";
@@ -29,7 +56,7 @@ fn rule_line_body_parses_body_only() -> Result<()> {
     assert_eq!(p.as_rule(), Rule::line_body);
     let mut inner = p.into_inner();
     let first = inner.next().unwrap();
-    assert_eq!(first.as_rule(), Rule::chat_message);
+    assert_eq!(first.as_rule(), Rule::channel_chat_message);
     Ok(())
 }
 
@@ -154,23 +181,24 @@ fn rule_channel_chat_message_parses_party_chat() -> Result<()> {
 #[test]
 fn rule_bare_chat_message_parses_player_chat() -> Result<()> {
     let input = "uskin432 (Warwick): ward top bush";
-    let mut pairs = LolChatParser::parse(Rule::bare_chat_message, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::bare_chat_message);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::bare_chat_message);
     Ok(())
 }
 
 #[test]
-fn rule_chat_message_parses_any_chat() -> Result<()> {
-    let input = "[All] piwkobb (Yone): hello this is all chat msg";
-    let mut pairs = LolChatParser::parse(Rule::chat_message, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::chat_message);
-    let mut inner = p.into_inner();
-    let first = inner.next().unwrap();
-    assert_eq!(first.as_rule(), Rule::channel_chat_message);
-
+fn rule_line_body_matches_the_actor_once() -> Result<()> {
+    let input = "uskin432 (Warwick) has slain Baron Nashor!";
+    let mut pairs = LolChatParser::parse(Rule::line_body, input)?;
+    let rules: Vec<Rule> = pairs
+        .next()
+        .unwrap()
+        .into_inner()
+        .map(|p| p.as_rule())
+        .collect();
+    assert_eq!(
+        rules,
+        vec![Rule::player_with_champion, Rule::objective_slain_event]
+    );
     Ok(())
 }
 
@@ -187,10 +215,7 @@ fn rule_chat_text_parses_full_message() -> Result<()> {
 #[test]
 fn rule_kill_first_blood_event_parses() -> Result<()> {
     let input = "kozakSyla (Lux) has drawn first blood!";
-    let mut pairs = LolChatParser::parse(Rule::kill_first_blood_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::kill_first_blood_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::kill_first_blood_event);
     Ok(())
 }
 
@@ -204,13 +229,63 @@ fn rule_shutdown_bounty_parses() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rule_shutdown_bounty_parses_variants() -> Result<()> {
+    for (input, inner) in [
+        ("(Bounty: 1,050G)", Rule::bounty_value),
+        ("(300G)", Rule::bounty_value),
+        ("(Bonus Bounty: unknown)", Rule::bounty_unparsed),
+    ] {
+        let mut pairs = LolChatParser::parse(Rule::shutdown_bounty, input)?;
+        let p = pairs.next().unwrap();
+        assert_eq!(p.as_str(), input);
+        assert_eq!(p.into_inner().next().unwrap().as_rule(), inner);
+    }
+    Ok(())
+}
+
 #[test]
 fn rule_kill_shutdown_event_parses() -> Result<()> {
     let input = "Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)";
-    let mut pairs = LolChatParser::parse(Rule::kill_shutdown_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::kill_shutdown_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::kill_shutdown_event);
+    Ok(())
+}
+
+#[test]
+fn rule_kill_slain_event_parses_champion_and_turret_kills() -> Result<()> {
+    for input in [
+        "Golf4f (Mel) has slain BorysBulba (Tahm Kench)!",
+        "An enemy turret has slain BorysBulba (Tahm Kench)!",
+        "The enemy minions have slain BorysBulba (Tahm Kench)!",
+    ] {
+        assert_eq!(event_rule(input)?, Rule::kill_slain_event);
+    }
+    Ok(())
+}
+
+#[test]
+fn rule_kill_execution_event_parses() -> Result<()> {
+    for input in [
+        "BorysBulba (Tahm Kench) has been executed!",
+        "BorysBulba (Tahm Kench) was executed by minions!",
+    ] {
+        assert_eq!(event_rule(input)?, Rule::kill_execution_event);
+    }
+    Ok(())
+}
+
+#[test]
+fn rule_non_champion_killer_picks_kind() -> Result<()> {
+    for (input, expected) in [
+        ("An allied turret", Rule::killer_turret),
+        ("minions", Rule::killer_minion),
+        ("the Elder Dragon", Rule::killer_monster),
+    ] {
+        let mut pairs = LolChatParser::parse(Rule::non_champion_killer, input)?;
+        let p = pairs.next().unwrap();
+        assert_eq!(p.as_str(), input);
+        assert_eq!(p.into_inner().next().unwrap().as_rule(), expected);
+    }
     Ok(())
 }
 
@@ -223,73 +298,115 @@ fn rule_team_name_parses_enemy_team() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn objective_rules_parse_monsters_structures_and_aces() -> Result<()> {
+    for (input, expected) in [
+        (
+            "Enemy team has slain the Infernal Drake!",
+            Rule::objective_slain_event,
+        ),
+        (
+            "piwkobb (Yone) has slain Baron Nashor!",
+            Rule::objective_slain_event,
+        ),
+        (
+            "Ally team has destroyed a turret!",
+            Rule::structure_destroyed_event,
+        ),
+        (
+            "An enemy inhibitor has been destroyed!",
+            Rule::structure_lost_event,
+        ),
+        (
+            "Your inhibitor has respawned!",
+            Rule::inhibitor_respawn_event,
+        ),
+        ("Baron Nashor has been slain!", Rule::monster_slain_event),
+        ("Ace!", Rule::ace_event),
+        ("Ally team has scored an ace!", Rule::ace_scored_event),
+        ("Enemy team has been aced!", Rule::ace_suffered_event),
+    ] {
+        assert_eq!(event_rule(input)?, expected);
+    }
+    Ok(())
+}
+
 #[test]
 fn rule_team_feat_event_parses() -> Result<()> {
     let input = "Enemy team has completed the Feat of Warfare!";
-    let mut pairs = LolChatParser::parse(Rule::team_feat_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::team_feat_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::team_feat_event);
     Ok(())
 }
 
 #[test]
 fn rule_purchase_event_parses() -> Result<()> {
     let input = "BorysBulba (Tahm Kench) purchased Control Ward";
-    let mut pairs = LolChatParser::parse(Rule::purchase_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::purchase_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::purchase_event);
     Ok(())
 }
 
 #[test]
 fn rule_target_player_event_parses() -> Result<()> {
     let input = "piwkobb (Yone) has targeted TheMiozl - (Renekton)";
-    let mut pairs = LolChatParser::parse(Rule::target_player_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::target_player_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::target_player_event);
     Ok(())
 }
 
 #[test]
 fn rule_target_objective_event_parses() -> Result<()> {
     let input = "kozakSyla (Lux) has targeted the Power Flower (33%)";
-    let mut pairs = LolChatParser::parse(Rule::target_objective_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::target_objective_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::target_objective_event);
     Ok(())
 }
 
 #[test]
-fn rule_rampage_event_parses() -> Result<()> {
+fn rule_streak_event_parses_rampage() -> Result<()> {
     let input = "kozakSyla (Lux) is on rampage!";
-    let mut pairs = LolChatParser::parse(Rule::rampage_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::rampage_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::streak_event);
+    Ok(())
+}
+
+#[test]
+fn rule_streak_event_parses_other_levels() -> Result<()> {
+    for input in [
+        "kozakSyla (Lux) is on a killing spree!",
+        "kozakSyla (Lux) is unstoppable!",
+        "kozakSyla (Lux) is legendary!",
+    ] {
+        assert_eq!(event_rule(input)?, Rule::streak_event);
+    }
+    Ok(())
+}
+
+#[test]
+fn rule_multikill_event_parses() -> Result<()> {
+    for input in [
+        "kozakSyla (Lux) got a double kill!",
+        "piwkobb (Yone) has scored a PENTAKILL!",
+    ] {
+        assert_eq!(event_rule(input)?, Rule::multikill_event);
+    }
+    Ok(())
+}
+
+#[test]
+fn rule_streak_ended_event_parses() -> Result<()> {
+    let input = "Golf4f (Mel) has ended kozakSyla (Lux)'s killing spree!";
+    assert_eq!(event_rule(input)?, Rule::streak_ended_event);
     Ok(())
 }
 
 #[test]
 fn rule_ping_on_the_way_event_parses() -> Result<()> {
     let input = "uskin432 (Warwick) is on the way";
-    let mut pairs = LolChatParser::parse(Rule::ping_on_the_way_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::ping_on_the_way_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::ping_on_the_way_event);
     Ok(())
 }
 
 #[test]
 fn rule_generic_player_event_parses() -> Result<()> {
     let input = "piwkobb (Yone) did something weird";
-    let mut pairs = LolChatParser::parse(Rule::generic_player_event, input)?;
-    let p = pairs.next().unwrap();
-    assert_eq!(p.as_rule(), Rule::generic_player_event);
-    assert_eq!(p.as_str(), input);
+    assert_eq!(event_rule(input)?, Rule::generic_player_event);
     Ok(())
 }
 
@@ -312,3 +429,46 @@ fn rule_generic_text_parses_any_text() -> Result<()> {
     assert_eq!(p.as_str(), input);
     Ok(())
 }
+
+#[test]
+fn rule_player_name_parses_riot_id_with_spaces() -> Result<()> {
+    let mut pairs = LolChatParser::parse(Rule::player_name, "Some Name#EUW1")?;
+    let p = pairs.next().unwrap();
+    assert_eq!(p.as_str(), "Some Name#EUW1");
+    let inner: Vec<_> = p.into_inner().map(|p| (p.as_rule(), p.as_str())).collect();
+    assert_eq!(
+        inner,
+        vec![(Rule::game_name, "Some Name"), (Rule::tag_line, "EUW1")]
+    );
+    Ok(())
+}
+
+#[test]
+fn rule_player_name_parses_non_latin_scripts() -> Result<()> {
+    for input in ["Козак Сила", "불꽃#KR1", "小龙"] {
+        let mut pairs = LolChatParser::parse(Rule::player_name, input)?;
+        assert_eq!(pairs.next().unwrap().as_str(), input);
+    }
+    Ok(())
+}
+
+#[test]
+fn rule_champion_name_parses_punctuation_and_ampersand() -> Result<()> {
+    for input in ["Nunu & Willump", "Dr. Mundo", "Kai'Sa", "Wukong (Legacy)"] {
+        let mut pairs = LolChatParser::parse(Rule::champion_name, input)?;
+        assert_eq!(pairs.next().unwrap().as_str(), input);
+    }
+    Ok(())
+}
+
+#[test]
+fn rule_player_with_champion_parses_parenthesized_champion() -> Result<()> {
+    let input = "piwkobb (Wukong (Legacy))";
+    let mut pairs = LolChatParser::parse(Rule::player_with_champion, input)?;
+    let p = pairs.next().unwrap();
+    assert_eq!(p.as_str(), input);
+    let champion = p.into_inner().nth(1).unwrap();
+    assert_eq!(champion.as_rule(), Rule::champion_name);
+    assert_eq!(champion.as_str(), "Wukong (Legacy)");
+    Ok(())
+}
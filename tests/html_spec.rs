@@ -0,0 +1,61 @@
+use anyhow::Result;
+use lol_chat_parser::{parse_log, render_html, to_json_string, OutputOptions, ParsedLog};
+use serde_json::Value;
+
+const LOG: &str = "\
+00:52 kozakSyla (Lux) has drawn first blood!
+08:00 Enemy team has slain the Infernal Drake!
+16:53 [All] piwkobb (Yone): </script><script>alert(1)</script> {{log}}
+17:34 [Team] kozakSyla (Lux): <!-- & \"quotes\"
+";
+
+/// Text of the `<script type="application/json" id="{id}">` element.
+fn script_text<'p>(page: &'p str, id: &str) -> &'p str {
+    let open = format!("<script type=\"application/json\" id=\"{id}\">");
+    let start = page.find(&open).unwrap() + open.len();
+    let end = start + page[start..].find("</script>").unwrap();
+    &page[start..end]
+}
+
+#[test]
+fn page_embeds_the_parsed_log() -> Result<()> {
+    let parsed = parse_log(LOG);
+    let page = render_html(&parsed, "Scrim 3")?;
+
+    let embedded: Value = serde_json::from_str(script_text(&page, "parsed-log"))?;
+    let printed: Value = serde_json::from_str(&to_json_string(&parsed, OutputOptions::default())?)?;
+    assert_eq!(embedded, printed);
+    let loaded: ParsedLog = serde_json::from_value(embedded)?;
+    assert_eq!(loaded, parsed);
+
+    let lines: Vec<String> = serde_json::from_str(script_text(&page, "rendered-lines"))?;
+    assert_eq!(lines.len(), parsed.timeline.len());
+    assert_eq!(lines[1], "08:00 Enemy team has slain the Infernal Drake!");
+    Ok(())
+}
+
+#[test]
+fn chat_text_cannot_break_out_of_the_page() -> Result<()> {
+    let page = render_html(&parse_log(LOG), "<b>Scrim</b> & co")?;
+
+    assert_eq!(page.matches("<script").count(), 3);
+    assert!(!page.contains("alert(1)</script>"));
+    assert!(page.contains("<title>&lt;b&gt;Scrim&lt;/b&gt; &amp; co</title>"));
+
+    let log: ParsedLog = serde_json::from_str(script_text(&page, "parsed-log"))?;
+    assert_eq!(
+        log.messages[0].text,
+        "</script><script>alert(1)</script> {{log}}"
+    );
+    assert_eq!(log.messages[1].text, "<!-- & \"quotes\"");
+    Ok(())
+}
+
+#[test]
+fn page_needs_no_external_assets() -> Result<()> {
+    let page = render_html(&parse_log(LOG), "Scrim")?;
+    for reference in ["http:", "https:", "src=", "<link", "@import", "url("] {
+        assert!(!page.contains(reference), "page references `{reference}`");
+    }
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anyhow::Result;
-use lol_chat_parser::{parse_log, ChatChannel, ParsedLog};
-use serde_json::{to_value, Value};
+use lol_chat_parser::{
+    ChatChannel, GameTime, LogEvent, ParsedLog, PingKind, StreakLevel, Team, parse_log,
+    parse_timestamp,
+};
+use serde_json::{Value, to_value};
 
 #[test]
 fn parses_sample_log_and_produces_expected_json_shape() -> Result<()> {
@@ -21,7 +24,7 @@ Type /help for a list of commands
 
     let parsed: ParsedLog = parse_log(log);
 
-    let mut players: Vec<_> = parsed.players.iter().map(|p| p.name.as_str()).collect();
+    let mut players: Vec<_> = parsed.players.iter().map(|p| p.name.as_ref()).collect();
     players.sort();
     assert!(players.contains(&"uskin432"));
     assert!(players.contains(&"kozakSyla"));
@@ -35,14 +38,15 @@ Type /help for a list of commands
     assert!(parsed.kills.iter().any(|k| k.is_shutdown));
 
     assert!(!parsed.events.is_empty());
-    assert!(parsed
-        .events
-        .iter()
-        .any(|o| o.description.contains("Feat of Warfare")));
-    assert!(parsed
-        .events
-        .iter()
-        .any(|o| o.description.contains("Power Flower")));
+    assert!(parsed.events.iter().any(|o| o.event
+        == LogEvent::TeamFeat {
+            team: Team::Enemy,
+            feat: "Feat of Warfare".into(),
+        }));
+    assert!(parsed.events.iter().any(|o| matches!(
+        &o.event,
+        LogEvent::TargetObjective { objective, percent: 33, .. } if objective == "Power Flower"
+    )));
 
     // 4. Чат-повідомлення — перевіряємо по каналах
     assert!(parsed.messages.iter().any(|m| m.channel == ChatChannel::Party));
@@ -90,3 +94,100 @@ Type /help for a list of commands
 
     Ok(())
 }
+
+#[test]
+fn chat_text_that_looks_like_an_event_stays_a_message() {
+    let log = "\
+05:00 [All] piwkobb (Yone): Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 1G)
+05:01 uskin432 (Warwick): kozakSyla (Lux) purchased Control Ward
+";
+
+    let parsed = parse_log(log);
+
+    assert_eq!(parsed.messages.len(), 2);
+    assert!(parsed.kills.is_empty());
+    assert!(parsed.events.is_empty());
+
+    let names: Vec<_> = parsed.players.iter().map(|p| p.name.as_ref()).collect();
+    assert_eq!(names, vec!["piwkobb", "uskin432"]);
+}
+
+#[test]
+fn chat_line_with_an_empty_message_is_kept() {
+    let log = "\
+00:42 Foo (Lux):
+00:43 [Team] Bar (Yone): \n";
+
+    let parsed = parse_log(log);
+
+    assert!(parsed.diagnostics.is_empty());
+    let texts: Vec<_> = parsed
+        .messages
+        .iter()
+        .map(|m| (m.channel, m.player.as_ref(), m.text.as_ref()))
+        .collect();
+    assert_eq!(
+        texts,
+        vec![(ChatChannel::Player, "Foo", ""), (ChatChannel::Team, "Bar", "")]
+    );
+
+    let names: Vec<_> = parsed.players.iter().map(|p| p.name.as_ref()).collect();
+    assert_eq!(names, vec!["Bar", "Foo"]);
+}
+
+#[test]
+fn parse_timestamp_reads_time_from_grammar() -> Result<()> {
+    assert_eq!(
+        parse_timestamp("13:10 BorysBulba (Tahm Kench) purchased Control Ward")?,
+        GameTime::from_minutes_seconds(13, 10)
+    );
+    assert!(parse_timestamp("Type /help for a list of commands").is_err());
+    Ok(())
+}
+
+#[test]
+fn events_are_typed_and_tagged_in_json() -> Result<()> {
+    let log = "\
+00:42 uskin432 (Warwick) is on the way
+13:10 BorysBulba (Tahm Kench) purchased Control Ward
+14:46 kozakSyla (Lux) is on rampage!
+15:40 piwkobb (Yone) has targeted TheMiozl - (Renekton)
+";
+
+    let parsed = parse_log(log);
+    let events: Vec<_> = parsed.events.iter().map(|e| &e.event).collect();
+
+    assert_eq!(
+        events,
+        vec![
+            &LogEvent::Ping {
+                player: "uskin432".into(),
+                champion: "Warwick".into(),
+                kind: PingKind::OnTheWay,
+            },
+            &LogEvent::Purchase {
+                player: "BorysBulba".into(),
+                champion: "Tahm Kench".into(),
+                item: "Control Ward".into(),
+            },
+            &LogEvent::Streak {
+                player: "kozakSyla".into(),
+                champion: "Lux".into(),
+                level: StreakLevel::Rampage,
+            },
+            &LogEvent::TargetPlayer {
+                player: "piwkobb".into(),
+                champion: "Yone".into(),
+                target: "TheMiozl".into(),
+                target_champion: "Renekton".into(),
+            },
+        ]
+    );
+
+    let json: Value = to_value(&parsed.events[1])?;
+    assert_eq!(json["type"], "purchase");
+    assert_eq!(json["time"], "13:10");
+    assert_eq!(json["item"], "Control Ward");
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use lol_chat_parser::{BountyStatus, KillerKind, Severity, parse_log};
+
+#[test]
+fn regular_champion_kills_are_recognized() {
+    let parsed = parse_log("05:12 Golf4f (Mel) has slain BorysBulba (Tahm Kench)!");
+
+    assert_eq!(parsed.kills.len(), 1);
+    let kill = &parsed.kills[0];
+    assert_eq!(kill.killer.as_deref(), Some("Golf4f"));
+    assert_eq!(kill.killer_champion.as_deref(), Some("Mel"));
+    assert_eq!(kill.killer_kind, KillerKind::Champion);
+    assert_eq!(kill.victim.as_deref(), Some("BorysBulba"));
+    assert_eq!(kill.victim_champion.as_deref(), Some("Tahm Kench"));
+    assert!(!kill.is_shutdown && !kill.is_first_blood && !kill.is_execution);
+    assert!(parsed.diagnostics.is_empty());
+}
+
+#[test]
+fn non_champion_killers_get_their_kind() {
+    let log = "\
+06:00 An enemy turret has slain BorysBulba (Tahm Kench)!
+06:10 Minions have slain nobody
+06:20 Baron Nashor has slain piwkobb (Yone)!
+06:30 The enemy minions have slain kozakSyla (Lux)!
+";
+
+    let parsed = parse_log(log);
+    let kills: Vec<_> = parsed
+        .kills
+        .iter()
+        .map(|k| (k.killer.as_deref(), k.killer_kind, k.victim.as_deref()))
+        .collect();
+
+    assert_eq!(
+        kills,
+        vec![
+            (
+                Some("An enemy turret"),
+                KillerKind::Turret,
+                Some("BorysBulba")
+            ),
+            (Some("Baron Nashor"), KillerKind::Monster, Some("piwkobb")),
+            (
+                Some("The enemy minions"),
+                KillerKind::Minion,
+                Some("kozakSyla")
+            ),
+        ]
+    );
+    assert!(parsed.kills.iter().all(|k| k.killer_champion.is_none()));
+    assert_eq!(parsed.unclassified.len(), 1);
+}
+
+#[test]
+fn executions_are_kills_without_a_champion_killer() {
+    let log = "\
+07:00 BorysBulba (Tahm Kench) has been executed!
+07:10 piwkobb (Yone) has been executed by an allied tower!
+";
+
+    let parsed = parse_log(log);
+
+    assert_eq!(parsed.kills.len(), 2);
+    assert!(parsed.kills.iter().all(|k| k.is_execution));
+
+    assert_eq!(parsed.kills[0].killer, None);
+    assert_eq!(parsed.kills[0].killer_kind, KillerKind::Unknown);
+    assert_eq!(parsed.kills[0].victim.as_deref(), Some("BorysBulba"));
+
+    assert_eq!(parsed.kills[1].killer.as_deref(), Some("an allied tower"));
+    assert_eq!(parsed.kills[1].killer_kind, KillerKind::Turret);
+    assert_eq!(parsed.kills[1].victim.as_deref(), Some("piwkobb"));
+
+    let names: Vec<_> = parsed.players.iter().map(|p| p.name.as_ref()).collect();
+    assert_eq!(names, vec!["BorysBulba", "piwkobb"]);
+}
+
+#[test]
+fn shutdown_bounties_tolerate_real_world_variants() {
+    let log = "\
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+13:06 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 1,050G)
+13:07 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bounty: 300G)
+13:08 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonusbelohnung: 1.200 Gold)
+13:09 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)!
+13:10 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: ???)
+";
+
+    let parsed = parse_log(log);
+    let bounties: Vec<_> = parsed
+        .kills
+        .iter()
+        .map(|k| (k.bounty, k.bounty_text.as_deref(), k.bounty_status))
+        .collect();
+
+    assert_eq!(
+        bounties,
+        vec![
+            (Some(149), Some("Bonus Bounty: 149G"), BountyStatus::Parsed),
+            (
+                Some(1050),
+                Some("Bonus Bounty: 1,050G"),
+                BountyStatus::Parsed
+            ),
+            (Some(300), Some("Bounty: 300G"), BountyStatus::Parsed),
+            (
+                Some(1200),
+                Some("Bonusbelohnung: 1.200 Gold"),
+                BountyStatus::Parsed
+            ),
+            (None, None, BountyStatus::Absent),
+            (None, Some("Bonus Bounty: ???"), BountyStatus::Unparseable),
+        ]
+    );
+    assert!(parsed.kills.iter().all(|k| k.is_shutdown));
+
+    assert_eq!(parsed.diagnostics.len(), 1);
+    assert_eq!(parsed.diagnostics[0].line, 6);
+    assert_eq!(parsed.diagnostics[0].severity, Severity::Warning);
+}
@@ -0,0 +1,200 @@
+use lol_chat_parser::{
+    parse_log, parse_log_with_locale, render_log, to_json_string, Locale, OutputOptions,
+};
+
+const ENGLISH_LOG: &str = "\
+00:42 uskin432 (Warwick) is on the way
+00:52 kozakSyla (Lux) has drawn first blood!
+02:24 Enemy team has completed the Feat of Warfare!
+05:10 An enemy turret has slain piwkobb (Yone)!
+08:00 Enemy team has slain the Infernal Drake!
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+14:46 kozakSyla (Lux) is on rampage!
+15:40 piwkobb (Yone) has targeted TheMiozl - (Renekton)
+17:34 [Team] kozakSyla (Lux): hi in team chat
+20:00 Golf4f (Mel) has destroyed an enemy turret!
+";
+
+const UKRAINIAN_LOG: &str = "\
+00:42 uskin432 (Warwick) вже в дорозі
+00:52 kozakSyla (Lux) проливає першу кров!
+02:24 Ворожа команда виконує Подвиг війни!
+05:10 Ворожа башта вбиває piwkobb (Yone)!
+08:00 Ворожа команда вбиває Пекельного дракона!
+13:05 Golf4f (Mel) зупиняє BorysBulba (Tahm Kench)! (Бонусна нагорода: 149G)
+13:08 [Група] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+14:46 kozakSyla (Lux) лютує!
+15:40 piwkobb (Yone) націлюється на TheMiozl - (Renekton)
+17:34 [Команда] kozakSyla (Lux): hi in team chat
+20:00 Golf4f (Mel) руйнує ворожу башту!
+";
+
+const POLISH_LOG: &str = "\
+00:42 uskin432 (Warwick) jest w drodze
+00:52 kozakSyla (Lux) przelewa pierwszą krew!
+02:24 Drużyna wroga ukończyła Wyczyn Wojenny!
+05:10 Wroga wieża zabija piwkobb (Yone)!
+08:00 Drużyna wroga zabija Piekielnego Smoka!
+13:05 Golf4f (Mel) powstrzymuje BorysBulba (Tahm Kench)! (Premia: 149G)
+13:08 [Grupa] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+14:46 kozakSyla (Lux) szaleje!
+15:40 piwkobb (Yone) celuje w TheMiozl - (Renekton)
+17:34 [Drużyna] kozakSyla (Lux): hi in team chat
+20:00 Golf4f (Mel) niszczy wrogą wieżę!
+";
+
+const GERMAN_LOG: &str = "\
+00:42 uskin432 (Warwick) ist unterwegs
+00:52 kozakSyla (Lux) hat das erste Blut vergossen!
+02:24 Gegnerisches Team hat die Heldentat der Kriegsführung abgeschlossen!
+05:10 Ein gegnerischer Turm hat piwkobb (Yone) getötet!
+08:00 Gegnerisches Team hat den Infernodrachen getötet!
+13:05 Golf4f (Mel) hat BorysBulba (Tahm Kench) ausgeschaltet! (Kopfgeld: 149G)
+13:08 [Gruppe] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+14:46 kozakSyla (Lux) wütet!
+15:40 piwkobb (Yone) hat TheMiozl - (Renekton) anvisiert
+17:34 [Team] kozakSyla (Lux): hi in team chat
+20:00 Golf4f (Mel) hat einen gegnerischen Turm zerstört!
+";
+
+/// Serializes the typed records only; raw bounty text and byte spans
+/// legitimately differ between languages.
+fn typed_output(input: &str) -> serde_json::Value {
+    let parsed = parse_log(input);
+    assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+
+    let options = OutputOptions {
+        include_source: false,
+        ..OutputOptions::default()
+    };
+    let mut value: serde_json::Value =
+        serde_json::from_str(&to_json_string(&parsed, options).unwrap()).unwrap();
+    for kill in value["kills"].as_array_mut().unwrap() {
+        kill["bounty_text"] = serde_json::Value::Null;
+    }
+    value
+}
+
+#[test]
+fn localized_logs_produce_the_same_records_as_english() {
+    let english = typed_output(ENGLISH_LOG);
+
+    for log in [UKRAINIAN_LOG, POLISH_LOG, GERMAN_LOG] {
+        assert_eq!(typed_output(log), english);
+    }
+}
+
+#[test]
+fn locale_is_detected_from_the_log() {
+    assert_eq!(Locale::detect(ENGLISH_LOG).code, "en");
+    assert_eq!(Locale::detect(UKRAINIAN_LOG).code, "uk");
+    assert_eq!(Locale::detect(POLISH_LOG).code, "pl");
+    assert_eq!(Locale::detect(GERMAN_LOG).code, "de");
+}
+
+#[test]
+fn chat_text_is_never_translated() {
+    let parsed = parse_log_with_locale(
+        "01:00 [Команда] piwkobb (Yone): Ворожа башта вбиває нас!",
+        &Locale::UKRAINIAN,
+    );
+
+    assert_eq!(parsed.messages.len(), 1);
+    assert_eq!(parsed.messages[0].text, "Ворожа башта вбиває нас!");
+}
+
+#[test]
+fn diagnostics_keep_the_original_localized_line() {
+    let line = "01:00 piwkobb (Yone) купує Контрольний тотем і ще щось";
+    let parsed = parse_log_with_locale(line, &Locale::UKRAINIAN);
+
+    assert_eq!(parsed.events.len(), 1);
+    assert!(parsed.diagnostics.is_empty());
+
+    let parsed =
+        parse_log_with_locale("01:00 piwkobb (Yone) робить щось дивне", &Locale::UKRAINIAN);
+    assert_eq!(parsed.unclassified[0].text, "робить щось дивне");
+    assert_eq!(
+        parsed.diagnostics[0].text,
+        "01:00 piwkobb (Yone) робить щось дивне"
+    );
+}
+
+#[test]
+fn locales_are_found_by_code() {
+    assert_eq!(Locale::from_code("PL"), Some(&Locale::POLISH));
+    assert_eq!(Locale::from_code("xx"), None);
+}
+
+/// One fixture per built-in table, using every channel tag and template,
+/// with the English it renders to.
+const FIXTURES: [(&Locale, &str, &str); 3] = [
+    (
+        &Locale::UKRAINIAN,
+        include_str!("fixtures/locale_uk.txt"),
+        include_str!("fixtures/locale_uk.en.txt"),
+    ),
+    (
+        &Locale::POLISH,
+        include_str!("fixtures/locale_pl.txt"),
+        include_str!("fixtures/locale_pl.en.txt"),
+    ),
+    (
+        &Locale::GERMAN,
+        include_str!("fixtures/locale_de.txt"),
+        include_str!("fixtures/locale_de.en.txt"),
+    ),
+];
+
+#[test]
+fn every_table_entry_is_covered_by_its_fixture() {
+    for (locale, fixture, _) in FIXTURES {
+        let bodies: Vec<&str> = fixture
+            .lines()
+            .filter_map(|line| line.split_once(' '))
+            .map(|(_, body)| body)
+            .collect();
+
+        for channel in locale.channels {
+            let single = Locale {
+                channels: std::slice::from_ref(channel),
+                templates: &[],
+                ..*locale
+            };
+            assert!(
+                bodies.iter().any(|body| !single.translate(body).is_empty()),
+                "{}: no line uses {channel:?}",
+                locale.code
+            );
+        }
+        for template in locale.templates {
+            let single = Locale {
+                channels: &[],
+                templates: std::slice::from_ref(template),
+                ..*locale
+            };
+            assert!(
+                bodies.iter().any(|body| !single.translate(body).is_empty()),
+                "{}: no line uses {template:?}",
+                locale.code
+            );
+        }
+    }
+}
+
+#[test]
+fn fixtures_render_to_english() {
+    for (locale, fixture, english) in FIXTURES {
+        let parsed = parse_log(fixture);
+        assert!(
+            parsed.diagnostics.is_empty(),
+            "{}: {:?}",
+            locale.code,
+            parsed.diagnostics
+        );
+        assert_eq!(parsed, parse_log_with_locale(fixture, locale));
+        assert_eq!(render_log(&parsed), english, "{}", locale.code);
+        assert_eq!(render_log(&parse_log(english)), english);
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use lol_chat_parser::{
+    parse_log, to_ndjson_string, LogParser, LogRecord, OutputOptions, PlayerSummary, TimeFormat,
+};
+use serde_json::Value;
+
+const LOG: &str = "\
+Type /help for a list of commands
+00:42 uskin432 (Warwick) is on the way
+00:52 kozakSyla (Lux) has drawn first blood!
+13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+15:00 piwkobb (Yone) has done something unusual
+";
+
+#[test]
+fn every_record_is_one_line_in_log_order_then_players() -> Result<()> {
+    let ndjson = to_ndjson_string(&parse_log(LOG), OutputOptions::default())?;
+    let kinds: Vec<String> = ndjson
+        .lines()
+        .map(|line| {
+            let value: Value = serde_json::from_str(line).unwrap();
+            value["kind"].as_str().unwrap().to_string()
+        })
+        .collect();
+
+    assert_eq!(
+        kinds,
+        [
+            "system",
+            "event",
+            "kill",
+            "message",
+            "unclassified",
+            "player",
+            "player",
+            "player"
+        ]
+    );
+    assert!(ndjson.ends_with("}\n"));
+    Ok(())
+}
+
+#[test]
+fn lines_load_back_as_streamed_records_and_players() -> Result<()> {
+    let options = OutputOptions {
+        time_format: TimeFormat::Seconds,
+        include_source: true,
+    };
+    let parsed = parse_log(LOG);
+    let ndjson = to_ndjson_string(&parsed, options)?;
+    let mut lines = ndjson.lines();
+
+    let mut parser = LogParser::new();
+    for line in LOG.lines() {
+        let record = parser.feed_line(line).unwrap().record;
+        let loaded: LogRecord = serde_json::from_str(lines.next().unwrap())?;
+        assert_eq!(loaded, record);
+    }
+
+    let players = lines
+        .map(|line| {
+            let value: Value = serde_json::from_str(line)?;
+            serde_json::from_value(value["record"].clone())
+        })
+        .collect::<serde_json::Result<Vec<PlayerSummary>>>()?;
+    assert_eq!(players, parsed.players);
+    Ok(())
+}
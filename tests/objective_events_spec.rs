@@ -0,0 +1,124 @@
+use lol_chat_parser::{parse_log, DragonElement, LogEvent, Objective, Team};
+
+fn objectives(log: &str) -> Vec<(Option<Team>, Option<String>, Objective)> {
+    parse_log(log)
+        .events
+        .into_iter()
+        .filter_map(|e| match e.event {
+            LogEvent::Objective {
+                team,
+                player,
+                objective,
+                ..
+            } => Some((team, player.map(String::from), objective)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn epic_monsters_are_typed() {
+    let log = "\
+05:10 Enemy team has slain the Infernal Drake!
+08:00 Ally team has slain the Void Grubs!
+14:00 Your team has slain the Rift Herald!
+20:00 piwkobb (Yone) has slain Atakhan!
+25:00 Enemy team has slain the Baron Nashor!
+30:00 Ally team has slain the Elder Dragon!
+31:00 Blue team has slain the Dragon!
+";
+
+    assert_eq!(
+        objectives(log),
+        vec![
+            (
+                Some(Team::Enemy),
+                None,
+                Objective::Dragon {
+                    element: Some(DragonElement::Infernal)
+                }
+            ),
+            (Some(Team::Ally), None, Objective::VoidGrubs),
+            (Some(Team::Ally), None, Objective::RiftHerald),
+            (None, Some("piwkobb".to_string()), Objective::Atakhan),
+            (Some(Team::Enemy), None, Objective::BaronNashor),
+            (Some(Team::Ally), None, Objective::ElderDragon),
+            (Some(Team::Blue), None, Objective::Dragon { element: None }),
+        ]
+    );
+}
+
+#[test]
+fn structures_respawns_and_aces_are_typed() {
+    let log = "\
+12:00 Ally team has destroyed a turret!
+12:30 Golf4f (Mel) has destroyed an enemy inhibitor!
+13:00 An enemy turret has been destroyed!
+13:10 Your nexus turret has been destroyed!
+17:00 Enemy inhibitor is respawning soon!
+17:30 Ace!
+18:00 Red team has scored an Ace!
+18:30 Enemy team has been aced!
+";
+
+    assert_eq!(
+        objectives(log),
+        vec![
+            (Some(Team::Ally), None, Objective::TurretDestroyed),
+            (
+                Some(Team::Ally),
+                Some("Golf4f".to_string()),
+                Objective::InhibitorDestroyed
+            ),
+            (Some(Team::Ally), None, Objective::TurretDestroyed),
+            (Some(Team::Enemy), None, Objective::NexusTurretDestroyed),
+            (Some(Team::Enemy), None, Objective::InhibitorRespawned),
+            (None, None, Objective::Ace),
+            (Some(Team::Red), None, Objective::Ace),
+            (Some(Team::Ally), None, Objective::Ace),
+        ]
+    );
+}
+
+#[test]
+fn player_structure_kills_take_the_team_from_the_article() {
+    let log = "\
+12:30 Golf4f (Mel) has destroyed an enemy inhibitor!
+12:40 BorysBulba (Tahm Kench) has destroyed your turret!
+12:50 Golf4f (Mel) destroyed a turret!
+13:00 An enemy inhibitor has been destroyed!
+13:10 Your turret has been destroyed!
+";
+
+    assert_eq!(
+        objectives(log),
+        vec![
+            (
+                Some(Team::Ally),
+                Some("Golf4f".to_string()),
+                Objective::InhibitorDestroyed
+            ),
+            (
+                Some(Team::Enemy),
+                Some("BorysBulba".to_string()),
+                Objective::TurretDestroyed
+            ),
+            (None, Some("Golf4f".to_string()), Objective::TurretDestroyed),
+            (Some(Team::Ally), None, Objective::InhibitorDestroyed),
+            (Some(Team::Enemy), None, Objective::TurretDestroyed),
+        ]
+    );
+}
+
+#[test]
+fn objectives_serialize_with_a_kind_tag() -> anyhow::Result<()> {
+    let parsed = parse_log("05:10 Enemy team has slain the Cloud Drake!");
+    let json = serde_json::to_value(&parsed.events[0])?;
+
+    assert_eq!(json["type"], "objective");
+    assert_eq!(json["team"], "enemy");
+    assert_eq!(json["objective"]["kind"], "dragon");
+    assert_eq!(json["objective"]["element"], "cloud");
+    assert!(parsed.diagnostics.is_empty());
+    Ok(())
+}
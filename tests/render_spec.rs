@@ -0,0 +1,409 @@
+use lol_chat_parser::{
+    parse_log, render_log, to_json_string, GameTime, LogParser, LolChatParser, OutputOptions,
+    ParsedLog, Rule,
+};
+use pest::Parser;
+use serde_json::Value;
+
+/// One canonical line per `line_body` alternative, named after the rule.
+const CANONICAL: &[(&str, &str)] = &[
+    (
+        "channel_chat_message",
+        "17:34 [Team] kozakSyla (Lux): hi in team chat",
+    ),
+    ("channel_chat_message", "16:53 [All] piwkobb (Yone): gg: wp"),
+    (
+        "channel_chat_message",
+        "13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges",
+    ),
+    ("bare_chat_message", "17:35 uskin432 (Warwick): some text"),
+    ("bare_chat_message", "17:36 uskin432 (Warwick):"),
+    (
+        "kill_shutdown_event",
+        "13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)",
+    ),
+    (
+        "kill_shutdown_event",
+        "13:06 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bounty: 1,050G)",
+    ),
+    (
+        "kill_shutdown_event",
+        "13:07 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)!",
+    ),
+    (
+        "kill_first_blood_event",
+        "00:52 kozakSyla (Lux) has drawn first blood!",
+    ),
+    (
+        "kill_slain_event",
+        "05:00 Golf4f (Mel) has slain BorysBulba (Tahm Kench)!",
+    ),
+    (
+        "kill_slain_event",
+        "05:10 An enemy turret has slain piwkobb (Yone)!",
+    ),
+    (
+        "kill_slain_event",
+        "05:20 The enemy minions have slain piwkobb (Yone)!",
+    ),
+    (
+        "kill_execution_event",
+        "06:00 BorysBulba (Tahm Kench) has been executed!",
+    ),
+    (
+        "kill_execution_event",
+        "06:10 BorysBulba (Tahm Kench) has been executed by a turret!",
+    ),
+    (
+        "objective_slain_event",
+        "08:00 Enemy team has slain the Infernal Drake!",
+    ),
+    (
+        "objective_slain_event",
+        "08:10 Blue team has slain the Dragon!",
+    ),
+    (
+        "objective_slain_event",
+        "08:20 Ally team has slain the Void Grubs!",
+    ),
+    (
+        "objective_slain_event",
+        "20:00 piwkobb (Yone) has slain Atakhan!",
+    ),
+    (
+        "objective_slain_event",
+        "25:00 Red team has slain the Baron Nashor!",
+    ),
+    (
+        "objective_slain_event",
+        "30:00 Ally team has slain the Elder Dragon!",
+    ),
+    (
+        "objective_slain_event",
+        "14:00 Enemy team has slain the Rift Herald!",
+    ),
+    (
+        "monster_slain_event",
+        "14:10 The Rift Herald has been slain!",
+    ),
+    (
+        "structure_destroyed_event",
+        "12:00 Ally team has destroyed a turret!",
+    ),
+    (
+        "structure_destroyed_event",
+        "12:30 Golf4f (Mel) has destroyed an enemy inhibitor!",
+    ),
+    (
+        "structure_destroyed_event",
+        "12:35 BorysBulba (Tahm Kench) has destroyed your turret!",
+    ),
+    (
+        "structure_destroyed_event",
+        "12:40 Enemy team has destroyed a nexus turret!",
+    ),
+    ("structure_lost_event", "13:00 A turret has been destroyed!"),
+    (
+        "inhibitor_respawn_event",
+        "17:00 Enemy inhibitor has respawned!",
+    ),
+    (
+        "inhibitor_respawn_event",
+        "17:10 Your inhibitor has respawned!",
+    ),
+    ("ace_event", "17:30 Ace!"),
+    ("ace_scored_event", "18:00 Red team has scored an Ace!"),
+    (
+        "ace_scored_event",
+        "18:10 kozakSyla (Lux) has scored an Ace!",
+    ),
+    (
+        "team_feat_event",
+        "02:24 Enemy team has completed the Feat of Warfare!",
+    ),
+    (
+        "purchase_event",
+        "13:10 BorysBulba (Tahm Kench) purchased Control Ward",
+    ),
+    (
+        "target_player_event",
+        "15:40 piwkobb (Yone) has targeted TheMiozl - (Renekton)",
+    ),
+    (
+        "target_objective_event",
+        "18:32 kozakSyla (Lux) has targeted the Power Flower (33%)",
+    ),
+    (
+        "multikill_event",
+        "19:00 kozakSyla (Lux) got a double kill!",
+    ),
+    ("multikill_event", "19:10 piwkobb (Yone) got a pentakill!"),
+    ("streak_event", "14:46 kozakSyla (Lux) is on rampage!"),
+    (
+        "streak_event",
+        "14:50 kozakSyla (Lux) is on a killing spree!",
+    ),
+    ("streak_event", "14:55 kozakSyla (Lux) is legendary!"),
+    (
+        "streak_ended_event",
+        "21:00 Golf4f (Mel) has ended kozakSyla (Lux)'s killing spree!",
+    ),
+    (
+        "streak_ended_event",
+        "21:10 Golf4f (Mel) has ended kozakSyla (Lux)'s streak!",
+    ),
+    (
+        "ping_on_the_way_event",
+        "00:42 uskin432 (Warwick) is on the way",
+    ),
+    (
+        "ping_on_the_way_event",
+        "00:43 uskin432 (Warwick) is missing",
+    ),
+    (
+        "ping_on_the_way_event",
+        "00:44 uskin432 (Warwick) is retreating",
+    ),
+    (
+        "ping_on_the_way_event",
+        "00:45 uskin432 (Warwick) is in danger",
+    ),
+    (
+        "ping_on_the_way_event",
+        "00:46 uskin432 (Warwick) needs vision",
+    ),
+    (
+        "generic_player_event",
+        "15:00 piwkobb (Yone) has done something unusual",
+    ),
+    ("generic_text", "15:05 Something entirely new happened"),
+    ("system line", "Type /help for a list of commands"),
+];
+
+/// Typed records only: diagnostics quote the original text and spans
+/// point into it, so both differ after rendering.
+fn records(log: &ParsedLog<'_>) -> Value {
+    let options = OutputOptions {
+        include_source: false,
+        ..OutputOptions::default()
+    };
+    let mut value: Value = serde_json::from_str(&to_json_string(log, options).unwrap()).unwrap();
+    value.as_object_mut().unwrap().remove("diagnostics");
+    value
+}
+
+/// Name of the event rule a line matched, after the actor that
+/// `line_body` matched first.
+fn matched_rule(line: &str) -> Option<String> {
+    let line = LolChatParser::parse(Rule::line, line).ok()?.next()?;
+    let rule = line.into_inner().nth(1)?.into_inner().last()?;
+    Some(format!("{:?}", rule.as_rule()))
+}
+
+#[test]
+fn every_line_body_rule_has_a_canonical_line() {
+    for (rule, line) in CANONICAL {
+        if *rule != "system line" {
+            assert_eq!(matched_rule(line).as_deref(), Some(*rule), "{line}");
+        }
+    }
+
+    // An ace suffered is written as the other team's ace scored.
+    let uncovered: Vec<String> = Rule::all_rules()
+        .iter()
+        .map(|rule| format!("{rule:?}"))
+        .filter(|name| {
+            (name.ends_with("_event") || name.ends_with("_chat_message") || name == "generic_text")
+                && name != "ace_suffered_event"
+        })
+        .filter(|name| !CANONICAL.iter().any(|(rule, _)| rule == name))
+        .collect();
+    assert!(uncovered.is_empty(), "no canonical line for {uncovered:?}");
+}
+
+/// `render(parse(line)) == line` for every canonical line at any time of
+/// the game; other spellings of the same time render as `MM:SS`.
+#[test]
+fn canonical_lines_render_back_unchanged() {
+    let times = [
+        0,
+        7,
+        5 * 60 + 7,
+        59 * 60 + 59,
+        65 * 60,
+        100 * 60 + 12,
+        10 * 3600,
+    ];
+    for (rule, line) in CANONICAL {
+        let Some((_, body)) = line.split_once(' ').filter(|_| *rule != "system line") else {
+            assert_eq!(render_log(&parse_log(line)), format!("{line}\n"));
+            continue;
+        };
+
+        for seconds in times {
+            let time = GameTime::from_seconds(seconds);
+            let canonical = format!("{time} {body}");
+            let rendered = render_log(&parse_log(&canonical));
+            assert_eq!(rendered, format!("{canonical}\n"), "{rule}");
+
+            let (m, s) = (time.minutes(), time.seconds());
+            let spellings = [
+                format!("{m}:{s:02} {body}"),
+                format!("{}:{:02}:{s:02} {body}", m / 60, m % 60),
+            ];
+            for spelling in spellings {
+                let parsed = parse_log(&spelling);
+                assert_eq!(render_log(&parsed), rendered, "{spelling}");
+                assert_eq!(
+                    records(&parse_log(&rendered)),
+                    records(&parsed),
+                    "{spelling}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn a_whole_log_renders_back_unchanged() {
+    let log: String = CANONICAL
+        .iter()
+        .map(|(_, line)| format!("{line}\n"))
+        .collect();
+    assert_eq!(render_log(&parse_log(&log)), log);
+
+    let example = include_str!("../lol_chat_example.txt");
+    assert_eq!(
+        render_log(&parse_log(example)).trim_end(),
+        example.trim_end()
+    );
+}
+
+#[test]
+fn variants_render_to_their_canonical_line() {
+    let cases = [
+        (
+            "1:40:12 [All] piwkobb (Yone): late",
+            "100:12 [All] piwkobb (Yone): late",
+        ),
+        (
+            "25:00 Your team has slain the Baron!",
+            "25:00 Ally team has slain the Baron Nashor!",
+        ),
+        (
+            "08:00 piwkobb (Yone) slew the cloud dragon!",
+            "08:00 piwkobb (Yone) has slain Cloud Drake!",
+        ),
+        (
+            "19:10 piwkobb (Yone) has scored a PENTAKILL!",
+            "19:10 piwkobb (Yone) got a pentakill!",
+        ),
+        (
+            "13:00 An enemy turret has been destroyed!",
+            "13:00 Ally team has destroyed a turret!",
+        ),
+        (
+            "13:10 Your nexus turret has been destroyed!",
+            "13:10 Enemy team has destroyed a nexus turret!",
+        ),
+        (
+            "18:00 Enemy team has been aced!",
+            "18:00 Ally team has scored an Ace!",
+        ),
+        (
+            "05:20 The enemy minions has slain piwkobb (Yone)!",
+            "05:20 The enemy minions have slain piwkobb (Yone)!",
+        ),
+        (
+            "17:00 Enemy inhibitor is respawning soon!",
+            "17:00 Enemy inhibitor has respawned!",
+        ),
+        (
+            "21:10 Golf4f (Mel) ended kozakSyla (Lux)'s streak!",
+            "21:10 Golf4f (Mel) has ended kozakSyla (Lux)'s streak!",
+        ),
+        (
+            "18:32 kozakSyla (Lux) has targeted Power Flower (33%)",
+            "18:32 kozakSyla (Lux) has targeted the Power Flower (33%)",
+        ),
+        (
+            "06:10 BorysBulba (Tahm Kench) was executed!",
+            "06:10 BorysBulba (Tahm Kench) has been executed!",
+        ),
+        (
+            "00:42 uskin432 (Warwick) вже в дорозі",
+            "00:42 uskin432 (Warwick) is on the way",
+        ),
+    ];
+
+    for (messy, canonical) in cases {
+        let parsed = parse_log(messy);
+        let rendered = render_log(&parsed);
+        assert_eq!(rendered, format!("{canonical}\n"), "{messy}");
+        assert_eq!(records(&parse_log(&rendered)), records(&parsed), "{messy}");
+    }
+}
+
+/// Every event template crossed with awkward player and champion names.
+#[test]
+fn generated_lines_round_trip() {
+    let players = [
+        "uskin432",
+        "Some Name#EUW1",
+        "Козак Сила",
+        "불꽃#KR1",
+        "x_X-1.'",
+    ];
+    let champions = [
+        "Lux",
+        "Kai'Sa",
+        "Dr. Mundo",
+        "Nunu & Willump",
+        "Wukong (Legacy)",
+    ];
+    let templates = [
+        "[All] {a}: hello (there)!",
+        "{a}: text",
+        "{a} has shut down {b}! (Bonus Bounty: 300G)",
+        "{a} has drawn first blood!",
+        "{a} has slain {b}!",
+        "{b} has been executed by an enemy turret!",
+        "{a} has slain Baron Nashor!",
+        "{a} has destroyed an enemy turret!",
+        "{a} has scored an Ace!",
+        "{a} purchased Blade of the Ruined King",
+        "{a} has targeted {b}",
+        "{a} has targeted the Power Flower (100%)",
+        "{a} got a triple kill!",
+        "{a} is godlike!",
+        "{a} has ended {b}'s rampage!",
+        "{a} needs vision",
+        "{a} did a backflip",
+    ];
+
+    let mut parser = LogParser::new();
+    for (i, template) in templates.iter().enumerate() {
+        for (j, player) in players.iter().enumerate() {
+            let champion = champions[(i + j) % champions.len()];
+            let other = players[(j + 1) % players.len()];
+            let other_champion = champions[(i + j + 1) % champions.len()];
+            let line = format!(
+                "{:02}:{:02} {}",
+                i,
+                j,
+                template
+                    .replace("{a}", &format!("{player} ({champion})"))
+                    .replace("{b}", &format!("{other} ({other_champion})"))
+            );
+            // `{b}` in a targeting line is written `name - (champion)`.
+            let line = line.replace(
+                &format!("targeted {other} ("),
+                &format!("targeted {other} - ("),
+            );
+
+            let parsed = parser.feed_line_owned(&line).unwrap();
+            let record = parsed.record;
+            assert_eq!(record.to_string(), line);
+        }
+    }
+}
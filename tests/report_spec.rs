@@ -0,0 +1,94 @@
+use lol_chat_parser::{parse_log, render_report, ReportFormat};
+
+const LOG: &str = "\
+00:52 kozakSyla (Lux) has drawn first blood!
+08:00 Enemy team has slain the Infernal Drake!
+12:30 Golf4f (Mel) has destroyed an enemy inhibitor!
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bounty: 1,050G)
+13:10 BorysBulba (Tahm Kench) purchased Control Ward
+16:53 [All] piwkobb (Yone): gg | wp
+17:34 [Team] kozakSyla (Lux): hi in team chat
+17:35 [Team] Golf4f (Mel): ok
+";
+
+/// Lines of the section headed `title` up to the next heading.
+fn section<'r>(report: &'r str, title: &str) -> Vec<&'r str> {
+    report
+        .lines()
+        .skip_while(|line| *line != title)
+        .skip(1)
+        .take_while(|line| !line.starts_with("## "))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+#[test]
+fn overview_counts_are_singular_for_one() {
+    let report = render_report(
+        &parse_log("00:10 [All] piwkobb (Yone): glhf"),
+        ReportFormat::Markdown,
+    );
+    assert!(
+        report.starts_with("# Match report\n\nLength 00:10: 1 player, 0 kills, 1 chat message.\n")
+    );
+}
+
+#[test]
+fn markdown_report_has_every_section() {
+    let report = render_report(&parse_log(LOG), ReportFormat::Markdown);
+    assert!(report
+        .starts_with("# Match report\n\nLength 17:35: 4 players, 2 kills, 3 chat messages.\n"));
+
+    assert!(section(&report, "## Roster").contains(&"| BorysBulba | Tahm Kench |"));
+    assert_eq!(
+        section(&report, "## First blood")[2],
+        "| 00:52 | kozakSyla (Lux) |  |"
+    );
+    assert_eq!(
+        section(&report, "## Shutdowns")[2],
+        "| 13:05 | Golf4f (Mel) | BorysBulba (Tahm Kench) | 1050G |"
+    );
+    assert_eq!(
+        section(&report, "## Objectives")[2..],
+        [
+            "| 08:00 | Enemy team has slain the Infernal Drake! |",
+            "| 12:30 | Golf4f (Mel) has destroyed an enemy inhibitor! |",
+        ]
+    );
+    assert_eq!(
+        section(&report, "## Chat")[2..],
+        ["| All | 1 | 1 |", "| Team | 2 | 2 |"]
+    );
+    assert_eq!(
+        section(&report, "## Notable quotes"),
+        [
+            "> \"gg \\| wp\" - piwkobb (Yone), 16:53",
+            "> \"hi in team chat\" - kozakSyla (Lux), 17:34",
+            "> \"ok\" - Golf4f (Mel), 17:35",
+        ]
+    );
+}
+
+#[test]
+fn text_report_aligns_columns() {
+    let report = render_report(&parse_log(LOG), ReportFormat::Text);
+    assert!(report.contains(
+        "\
+Shutdowns
+---------
+Time   Killer        Victim                   Bounty
+13:05  Golf4f (Mel)  BorysBulba (Tahm Kench)  1050G
+"
+    ));
+    assert!(report.contains("  \"gg | wp\" - piwkobb (Yone), 16:53\n"));
+}
+
+#[test]
+fn empty_sections_say_none() {
+    let report = render_report(
+        &parse_log("Type /help for a list of commands"),
+        ReportFormat::Markdown,
+    );
+    assert_eq!(section(&report, "## Shutdowns"), ["None."]);
+    assert_eq!(section(&report, "## Notable quotes"), ["None."]);
+}
@@ -0,0 +1,56 @@
+use lol_chat_parser::{parse_log, LogEvent, RiotId};
+
+#[test]
+fn riot_ids_with_spaces_and_unicode_are_parsed() {
+    let log = "\
+01:00 [All] Some Name#EUW1 (Yone): hello
+01:05 Козак Сила#UA1 (Lux) is on the way
+01:10 불꽃 (Ahri) has drawn first blood!
+01:15 小龙#CN1 (Jinx): gg
+01:20 Some Name#EUW1 (Yone) has targeted The Miozl - (Renekton)
+";
+
+    let parsed = parse_log(log);
+
+    assert_eq!(parsed.messages[0].player, "Some Name#EUW1");
+    assert_eq!(parsed.kills[0].killer.as_deref(), Some("불꽃"));
+    assert!(matches!(
+        &parsed.events[1].event,
+        LogEvent::TargetPlayer { target, target_champion, .. }
+            if target == "The Miozl" && target_champion == "Renekton"
+    ));
+
+    let ids: Vec<_> = parsed
+        .players
+        .iter()
+        .map(|p| (p.riot_id.game_name.as_ref(), p.riot_id.tag_line.as_deref()))
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            ("Some Name", Some("EUW1")),
+            ("The Miozl", None),
+            ("Козак Сила", Some("UA1")),
+            ("小龙", Some("CN1")),
+            ("불꽃", None),
+        ]
+    );
+    assert!(parsed.diagnostics.is_empty());
+}
+
+#[test]
+fn riot_id_round_trips_through_display() -> anyhow::Result<()> {
+    let id = RiotId::parse("Some Name#EUW1");
+    assert_eq!(id.game_name, "Some Name");
+    assert_eq!(id.tag_line.as_deref(), Some("EUW1"));
+    assert_eq!(id.to_string(), "Some Name#EUW1");
+
+    assert_eq!(RiotId::parse("uskin432").to_string(), "uskin432");
+
+    let parsed = parse_log("01:00 [All] Some Name#EUW1 (Yone): hello");
+    let json = serde_json::to_value(&parsed.players[0])?;
+    assert_eq!(json["name"], "Some Name#EUW1");
+    assert_eq!(json["game_name"], "Some Name");
+    assert_eq!(json["tag_line"], "EUW1");
+    Ok(())
+}
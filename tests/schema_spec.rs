@@ -0,0 +1,176 @@
+use anyhow::Result;
+use lol_chat_parser::{
+    json_schema, parse_log, to_json_string, LogParser, LogRecord, OutputOptions, ParsedLog,
+    RecordKind, SourceSpan, TimeFormat, SCHEMA_VERSION,
+};
+use serde_json::Value;
+
+const LOG: &str = "\
+Type /help for a list of commands
+00:42 uskin432 (Warwick) is on the way
+00:52 kozakSyla (Lux) has drawn first blood!
+08:00 Enemy team has slain the Infernal Drake!
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+15:00 piwkobb (Yone) has done something unusual
+15:10 Some Name#EUW1 (Kaisa) purchased Control Ward
+";
+
+#[test]
+fn parsed_logs_round_trip_through_json() -> Result<()> {
+    let parsed = parse_log(LOG);
+    assert!(!parsed.diagnostics.is_empty());
+
+    for time_format in [TimeFormat::Clock, TimeFormat::Seconds] {
+        let options = OutputOptions {
+            time_format,
+            ..OutputOptions::default()
+        };
+        let json = to_json_string(&parsed, options)?;
+        let loaded: ParsedLog<'static> = serde_json::from_str(&json)?;
+        assert_eq!(loaded, parsed);
+    }
+    Ok(())
+}
+
+#[test]
+fn output_without_source_loads_with_empty_spans() -> Result<()> {
+    let options = OutputOptions {
+        include_source: false,
+        ..OutputOptions::default()
+    };
+    let json = to_json_string(&parse_log(LOG), options)?;
+    let loaded: ParsedLog = serde_json::from_str(&json)?;
+
+    assert_eq!(loaded.messages[0].source, SourceSpan::default());
+    assert_eq!(loaded.messages[0].player, "piwkobb");
+    Ok(())
+}
+
+#[test]
+fn schema_version_is_written_and_checked() -> Result<()> {
+    let mut json: Value = serde_json::to_value(parse_log(LOG))?;
+    assert_eq!(json["schema_version"], SCHEMA_VERSION);
+
+    json.as_object_mut().unwrap().remove("schema_version");
+    let loaded: ParsedLog = serde_json::from_value(json.clone())?;
+    assert_eq!(loaded.schema_version, 1);
+
+    json["schema_version"] = (SCHEMA_VERSION + 1).into();
+    let err = serde_json::from_value::<ParsedLog>(json).unwrap_err();
+    assert!(err.to_string().contains("unsupported schema_version"));
+    Ok(())
+}
+
+#[test]
+fn streamed_records_round_trip_through_json() -> Result<()> {
+    let mut parser = LogParser::new();
+    for line in LOG.lines() {
+        let Some(record) = parser.feed_line(line).map(|l| l.record) else {
+            continue;
+        };
+        let json = to_json_string(&record, OutputOptions::default())?;
+        let loaded: LogRecord = serde_json::from_str(&json)?;
+        assert_eq!(loaded, record);
+    }
+    Ok(())
+}
+
+/// One line of every record kind and event variant, with an hour-long time.
+const EVERY_KIND: &str = "\
+Type /help for a list of commands
+01:00 [All] piwkobb (Yone): gg: wp
+01:05 uskin432 (Warwick): ward top bush
+01:10 kozakSyla (Lux) has drawn first blood!
+02:00 Golf4f (Mel) has slain BorysBulba (Tahm Kench)!
+02:10 An enemy turret has slain BorysBulba (Tahm Kench)!
+02:20 BorysBulba (Tahm Kench) was executed by minions!
+03:00 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: unknown)
+04:00 BorysBulba (Tahm Kench) purchased Control Ward
+04:10 uskin432 (Warwick) is on the way
+04:20 kozakSyla (Lux) is on a killing spree!
+04:30 kozakSyla (Lux) got a double kill!
+04:40 Golf4f (Mel) has ended kozakSyla (Lux)'s killing spree!
+04:50 piwkobb (Yone) has targeted TheMiozl - (Renekton)
+05:00 kozakSyla (Lux) has targeted the Power Flower (33%)
+06:00 Enemy team has completed the Feat of Warfare!
+08:00 Enemy team has slain the Infernal Drake!
+09:00 Baron Nashor has been slain!
+10:00 Ally team has destroyed a turret!
+10:10 An enemy inhibitor has been destroyed!
+10:20 Your inhibitor has respawned!
+11:00 Ace!
+12:00 something nobody recognizes
+1:40:12 piwkobb (Yone) has done something unusual
+";
+
+#[test]
+fn output_validates_against_the_schema() -> Result<()> {
+    let validator = jsonschema::validator_for(&json_schema())?;
+    let parsed = parse_log(EVERY_KIND);
+    for kind in [
+        RecordKind::Message,
+        RecordKind::Kill,
+        RecordKind::Event,
+        RecordKind::System,
+        RecordKind::Unclassified,
+    ] {
+        assert!(
+            parsed.timeline.iter().any(|r| r.kind == kind),
+            "no {kind:?}"
+        );
+    }
+    assert!(!parsed.diagnostics.is_empty());
+
+    for time_format in [TimeFormat::Clock, TimeFormat::Seconds] {
+        for include_source in [true, false] {
+            let options = OutputOptions {
+                time_format,
+                include_source,
+            };
+            let output: Value = serde_json::from_str(&to_json_string(&parsed, options)?)?;
+            let errors: Vec<String> = validator
+                .iter_errors(&output)
+                .map(|e| format!("{} at {}", e, e.instance_path()))
+                .collect();
+            assert!(errors.is_empty(), "{time_format:?}: {errors:#?}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn schema_rejects_mistyped_fields() -> Result<()> {
+    let validator = jsonschema::validator_for(&json_schema())?;
+    let mut output: Value = serde_json::to_value(parse_log(EVERY_KIND))?;
+    assert!(validator.is_valid(&output));
+
+    output["kills"][0]["time"] = "late".into();
+    assert!(!validator.is_valid(&output));
+    Ok(())
+}
+
+#[test]
+fn schema_describes_every_output_field() -> Result<()> {
+    let schema = json_schema();
+    let output: Value = serde_json::to_value(parse_log(LOG))?;
+
+    let properties = schema["properties"].as_object().unwrap();
+    for key in output.as_object().unwrap().keys() {
+        assert!(properties.contains_key(key), "`{key}` missing from schema");
+    }
+    assert_eq!(schema["required"][0], "schema_version");
+    assert_eq!(
+        schema["properties"]["schema_version"]["maximum"],
+        SCHEMA_VERSION
+    );
+
+    let definitions = schema["$defs"].as_object().unwrap();
+    for name in ["ChatMessage", "KillEvent", "ObjectiveEvent", "GameTime"] {
+        assert!(
+            definitions.contains_key(name),
+            "`{name}` missing from schema"
+        );
+    }
+    Ok(())
+}
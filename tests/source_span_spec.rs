@@ -0,0 +1,79 @@
+use anyhow::Result;
+use lol_chat_parser::{parse_log, to_json_string, OutputOptions, SourceSpan, TimeFormat};
+use serde_json::{to_value, Value};
+
+const LOG: &str = "Type /help for a list of commands\r
+00:52 kozakSyla (Lux) has drawn first blood!\r
+\r
+  13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges\r
+13:10 BorysBulba (Tahm Kench) purchased Control Ward";
+
+#[test]
+fn every_record_points_back_to_its_source_line() {
+    let parsed = parse_log(LOG);
+
+    assert_eq!(parsed.system[0].source.line, 1);
+    assert_eq!(parsed.kills[0].source.line, 2);
+    assert_eq!(parsed.messages[0].source.line, 4);
+    assert_eq!(parsed.events[0].source.line, 5);
+
+    assert_eq!(
+        parsed.messages[0].source.text(LOG),
+        Some("13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges")
+    );
+    assert_eq!(
+        parsed.kills[0].source,
+        SourceSpan {
+            line: 2,
+            start: 35,
+            end: 35 + "00:52 kozakSyla (Lux) has drawn first blood!".len(),
+        }
+    );
+    assert_eq!(
+        parsed.events[0].source.text(LOG),
+        Some("13:10 BorysBulba (Tahm Kench) purchased Control Ward")
+    );
+}
+
+#[test]
+fn source_can_be_hidden_from_json() -> Result<()> {
+    let parsed = parse_log(LOG);
+
+    let json: Value = to_value(&parsed)?;
+    assert_eq!(json["kills"][0]["source"]["line"], 2);
+
+    let options = OutputOptions {
+        include_source: false,
+        ..OutputOptions::default()
+    };
+    let json: Value = serde_json::from_str(&to_json_string(&parsed, options)?)?;
+    assert!(json["kills"][0].get("source").is_none());
+    assert!(json["events"][0].get("source").is_none());
+    assert!(json["messages"][0].get("source").is_none());
+    assert!(json["system"][0].get("source").is_none());
+    Ok(())
+}
+
+#[test]
+fn options_travel_with_the_value_to_other_threads() -> Result<()> {
+    let parsed = parse_log(LOG);
+    let options = OutputOptions {
+        time_format: TimeFormat::Seconds,
+        include_source: false,
+    };
+
+    let json = std::thread::scope(|s| {
+        s.spawn(|| serde_json::to_value(options.apply(&parsed)))
+            .join()
+            .unwrap()
+    })?;
+    assert_eq!(json["kills"][0]["time"], 52);
+    assert!(json["kills"][0].get("source").is_none());
+    assert_eq!(json["events"][0]["time"], 790);
+    assert!(json["events"][0].get("source").is_none());
+    assert_eq!(json["events"][0]["item"], "Control Ward");
+
+    // The record itself still serializes with the defaults.
+    assert_eq!(to_value(&parsed.kills[0])?["time"], "00:52");
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anyhow::Result;
+use lol_chat_parser::{
+    parse_log, player_stats, to_json_string, MessageCounts, OutputOptions, PingCounts, PlayerStats,
+    TimeFormat,
+};
+
+const LOG: &str = "\
+00:42 uskin432 (Warwick) is on the way
+00:45 uskin432 (Warwick) is on the way
+00:46 uskin432 (Warwick) needs vision
+00:52 kozakSyla (Lux) has drawn first blood!
+05:00 Golf4f (Mel) has slain BorysBulba (Tahm Kench)!
+05:10 An enemy turret has slain piwkobb (Yone)!
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+13:06 BorysBulba (Tahm Kench) has shut down Golf4f (Mel)! (Bounty: 1,050G)
+13:10 BorysBulba (Tahm Kench) purchased Control Ward
+13:11 BorysBulba (Tahm Kench) purchased Stealth Ward
+15:40 piwkobb (Yone) has targeted BorysBulba - (Tahm Kench)
+18:32 piwkobb (Yone) has targeted the Power Flower (33%)
+16:53 [All] piwkobb (Yone): gg
+17:34 [Team] kozakSyla (Lux): hi in team chat
+17:35 [Team] piwkobb (Yone): ok
+21:00 piwkobb (Yone) did a backflip
+";
+
+fn stats_of<'s>(stats: &'s [PlayerStats<'_>], name: &str) -> &'s PlayerStats<'s> {
+    stats.iter().find(|s| s.name == name).unwrap()
+}
+
+#[test]
+fn kills_count_first_bloods_shutdowns_and_bounties() {
+    let stats = player_stats(&parse_log(LOG));
+
+    let kozak = stats_of(&stats, "kozakSyla");
+    assert_eq!(kozak.first_bloods, 1);
+
+    let golf = stats_of(&stats, "Golf4f");
+    assert_eq!((golf.shutdowns_dealt, golf.shutdowns_received), (1, 1));
+    assert_eq!(golf.bounty_gold, 149);
+
+    let borys = stats_of(&stats, "BorysBulba");
+    assert_eq!((borys.shutdowns_dealt, borys.shutdowns_received), (1, 1));
+    assert_eq!(borys.bounty_gold, 1050);
+    assert_eq!(borys.purchases, 2);
+    assert_eq!(borys.targets_received, 1);
+}
+
+#[test]
+fn events_and_messages_are_counted_per_kind() {
+    let stats = player_stats(&parse_log(LOG));
+
+    let uskin = stats_of(&stats, "uskin432");
+    assert_eq!(
+        uskin.pings,
+        PingCounts {
+            on_the_way: 2,
+            needs_vision: 1,
+            ..PingCounts::default()
+        }
+    );
+    assert_eq!(uskin.pings.total(), 3);
+
+    let piwko = stats_of(&stats, "piwkobb");
+    assert_eq!(
+        piwko.messages,
+        MessageCounts {
+            all: 1,
+            team: 1,
+            ..MessageCounts::default()
+        }
+    );
+    assert_eq!(piwko.targets_made, 2);
+    assert_eq!(piwko.targets_received, 0);
+}
+
+#[test]
+fn activity_spans_every_record_naming_the_player() {
+    let stats = player_stats(&parse_log(LOG));
+
+    let piwko = stats_of(&stats, "piwkobb");
+    assert_eq!(piwko.first_seen.unwrap().to_string(), "05:10");
+    assert_eq!(piwko.last_seen.unwrap().to_string(), "21:00");
+
+    let names: Vec<&str> = stats.iter().map(|s| s.name.as_ref()).collect();
+    assert_eq!(
+        names,
+        ["BorysBulba", "Golf4f", "kozakSyla", "piwkobb", "uskin432"]
+    );
+}
+
+#[test]
+fn stats_round_trip_through_json() -> Result<()> {
+    let stats = player_stats(&parse_log(LOG));
+    let options = OutputOptions {
+        time_format: TimeFormat::Seconds,
+        ..OutputOptions::default()
+    };
+    let json = to_json_string(&stats, options)?;
+    assert!(json.contains("\"first_seen\":42,"));
+
+    let loaded: Vec<PlayerStats> = serde_json::from_str(&json)?;
+    assert_eq!(loaded, stats);
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use lol_chat_parser::{parse_log, LogEvent, StreakLevel};
+
+#[test]
+fn multikills_and_streaks_are_typed() {
+    let log = "\
+20:01 kozakSyla (Lux) got a double kill!
+20:02 kozakSyla (Lux) got a triple kill!
+20:03 kozakSyla (Lux) has scored a quadra kill!
+20:04 kozakSyla (Lux) has scored a PENTAKILL!
+20:05 kozakSyla (Lux) is on a killing spree!
+20:06 kozakSyla (Lux) is on rampage!
+20:07 kozakSyla (Lux) is unstoppable!
+20:08 kozakSyla (Lux) is dominating!
+20:09 kozakSyla (Lux) is godlike!
+20:10 kozakSyla (Lux) is legendary!
+";
+
+    let parsed = parse_log(log);
+    let levels: Vec<_> = parsed
+        .events
+        .iter()
+        .filter_map(|e| match &e.event {
+            LogEvent::Streak { player, level, .. } if player == "kozakSyla" => Some(*level),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(
+        levels,
+        vec![
+            StreakLevel::DoubleKill,
+            StreakLevel::TripleKill,
+            StreakLevel::QuadraKill,
+            StreakLevel::PentaKill,
+            StreakLevel::KillingSpree,
+            StreakLevel::Rampage,
+            StreakLevel::Unstoppable,
+            StreakLevel::Dominating,
+            StreakLevel::Godlike,
+            StreakLevel::Legendary,
+        ]
+    );
+    assert_eq!(levels.iter().filter(|l| l.is_multikill()).count(), 4);
+    assert!(parsed.diagnostics.is_empty());
+}
+
+#[test]
+fn ended_streaks_link_both_players() {
+    let log = "\
+21:00 Golf4f (Mel) has ended kozakSyla (Lux)'s killing spree!
+21:30 piwkobb (Yone) ended Golf4f (Mel)'s streak!
+";
+
+    let parsed = parse_log(log);
+
+    assert_eq!(
+        parsed.events[0].event,
+        LogEvent::StreakEnded {
+            player: "Golf4f".into(),
+            champion: "Mel".into(),
+            victim: "kozakSyla".into(),
+            victim_champion: "Lux".into(),
+            level: Some(StreakLevel::KillingSpree),
+        }
+    );
+    assert!(matches!(
+        &parsed.events[1].event,
+        LogEvent::StreakEnded { level: None, victim, .. } if victim == "Golf4f"
+    ));
+
+    let names: Vec<_> = parsed.players.iter().map(|p| p.name.as_ref()).collect();
+    assert_eq!(names, vec!["Golf4f", "kozakSyla", "piwkobb"]);
+}
@@ -0,0 +1,129 @@
+use std::io::Cursor;
+
+use lol_chat_parser::{parse_log, LogParser, LogRecord, Record, RecordKind};
+
+const LOG: &str = "\
+Type /help for a list of commands
+00:42 uskin432 (Warwick) is on the way
+
+00:52 kozakSyla (Lux) has drawn first blood!
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+13:08 [Party] piwkobb (Yone): Cho'Gath Heartsteel - 20 charges
+15:00 piwkobb (Yone) has done something unusual
+";
+
+#[test]
+fn streamed_records_match_parse_log() -> anyhow::Result<()> {
+    let parsed = parse_log(LOG);
+    let mut parser = LogParser::new();
+    let streamed: Vec<_> = parser
+        .records(Cursor::new(LOG))
+        .collect::<std::io::Result<_>>()?;
+
+    assert_eq!(streamed.len(), parsed.timeline.len());
+    for (line, entry) in streamed.iter().zip(parsed.timeline()) {
+        assert_eq!(line.source.line, entry.line);
+        let same = match (&line.record, entry.record) {
+            (LogRecord::Message(a), Record::Message(b)) => a == b,
+            (LogRecord::Kill(a), Record::Kill(b)) => a == b,
+            (LogRecord::Event(a), Record::Event(b)) => a == b,
+            (LogRecord::System(a), Record::System(b)) => a == b,
+            (LogRecord::Unclassified(a), Record::Unclassified(b)) => a == b,
+            _ => false,
+        };
+        assert!(same, "line {} differs", line.source.line);
+    }
+
+    let diagnostics: Vec<_> = streamed.into_iter().flat_map(|l| l.diagnostics).collect();
+    assert_eq!(diagnostics, parsed.diagnostics);
+
+    parser = LogParser::new();
+    for line in LOG.lines() {
+        parser.feed_line(line);
+    }
+    assert_eq!(parser.players(), parsed.players);
+    Ok(())
+}
+
+#[test]
+fn feed_line_tracks_line_numbers_and_offsets() {
+    let mut parser = LogParser::new();
+
+    assert!(parser
+        .feed_line("Type /help for a list of commands")
+        .is_some());
+    assert!(parser.feed_line("   ").is_none());
+    let parsed = parser
+        .feed_line("  00:42 uskin432 (Warwick) is on the way\r\n")
+        .unwrap();
+
+    assert_eq!(parsed.source.line, 3);
+    assert_eq!(parsed.source.start, 34 + 4 + 2);
+    assert_eq!(
+        parsed.source.end - parsed.source.start,
+        "00:42 uskin432 (Warwick) is on the way".len()
+    );
+    assert_eq!(parsed.record.kind(), RecordKind::Event);
+}
+
+#[test]
+fn crlf_spans_match_parse_log() {
+    let log = LOG.replace('\n', "\r\n");
+    let expected: Vec<_> = parse_log(&log)
+        .timeline()
+        .map(|entry| {
+            let source = match entry.record {
+                Record::Message(r) => r.source,
+                Record::Kill(r) => r.source,
+                Record::Event(r) => r.source,
+                Record::System(r) => r.source,
+                Record::Unclassified(r) => r.source,
+            };
+            (source.line, source.start, source.end)
+        })
+        .collect();
+
+    let mut split = LogParser::new().with_crlf();
+    let mut kept = LogParser::new();
+    for (parser, lines) in [
+        (&mut split, log.lines().collect::<Vec<_>>()),
+        (&mut kept, log.split_inclusive('\n').collect()),
+    ] {
+        let streamed: Vec<_> = lines
+            .into_iter()
+            .filter_map(|line| parser.feed_line(line))
+            .map(|parsed| (parsed.source.line, parsed.source.start, parsed.source.end))
+            .collect();
+        assert_eq!(streamed, expected);
+    }
+
+    let (_, start, end) = expected[1];
+    assert_eq!(&log[start..end], "00:42 uskin432 (Warwick) is on the way");
+}
+
+#[test]
+fn player_registry_grows_as_lines_arrive() {
+    let mut parser = LogParser::new();
+    assert!(parser.players().is_empty());
+
+    parser.feed_line("00:42 uskin432 (Warwick) is on the way");
+    assert_eq!(parser.players().len(), 1);
+
+    parser.feed_line("00:52 kozakSyla (Lux) has drawn first blood!");
+    let names: Vec<_> = parser.players().into_iter().map(|p| p.name).collect();
+    assert_eq!(names, ["kozakSyla", "uskin432"]);
+}
+
+#[test]
+fn locale_is_picked_up_from_the_first_localized_line() {
+    let mut parser = LogParser::new();
+
+    parser.feed_line("00:42 uskin432 (Warwick) is on the way");
+    assert!(parser.locale().is_none());
+
+    let parsed = parser
+        .feed_line("00:52 kozakSyla (Lux) проливає першу кров!")
+        .unwrap();
+    assert!(matches!(parsed.record, LogRecord::Kill(ref k) if k.is_first_blood));
+    assert_eq!(parser.locale().map(|l| l.code), Some("uk"));
+}
@@ -0,0 +1,52 @@
+use lol_chat_parser::{Record, RecordKind, parse_log};
+
+const LOG: &str = "\
+Type /help for a list of commands
+13:05 Golf4f (Mel) has shut down BorysBulba (Tahm Kench)! (Bonus Bounty: 149G)
+
+13:05 [All] BorysBulba (Tahm Kench): ff
+13:06 Golf4f (Mel) did something weird
+13:10 BorysBulba (Tahm Kench) purchased Control Ward
+";
+
+#[test]
+fn timeline_keeps_original_order_and_line_numbers() {
+    let parsed = parse_log(LOG);
+
+    let entries: Vec<_> = parsed
+        .timeline()
+        .map(|e| {
+            (
+                e.line,
+                e.record.kind(),
+                e.record.time().map(|t| t.to_string()),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        entries,
+        vec![
+            (1, RecordKind::System, None),
+            (2, RecordKind::Kill, Some("13:05".to_string())),
+            (4, RecordKind::Message, Some("13:05".to_string())),
+            (5, RecordKind::Unclassified, Some("13:06".to_string())),
+            (6, RecordKind::Event, Some("13:10".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn timeline_entries_borrow_typed_records() {
+    let parsed = parse_log(LOG);
+    let mut timeline = parsed.timeline().skip(1);
+
+    match timeline.next().map(|e| e.record) {
+        Some(Record::Kill(kill)) => assert!(std::ptr::eq(kill, &parsed.kills[0])),
+        other => panic!("expected kill, got {other:?}"),
+    }
+    match timeline.next().map(|e| e.record) {
+        Some(Record::Message(msg)) => assert_eq!(msg.text, "ff"),
+        other => panic!("expected message, got {other:?}"),
+    }
+}